@@ -1,4 +1,9 @@
-use chrono::{NaiveDate, ParseError};
+use std::collections::HashSet;
+
+use chrono::format::ParseErrorKind;
+use chrono::{
+    DateTime, Datelike, Months, NaiveDate, NaiveDateTime, NaiveTime, ParseError, TimeDelta, Weekday,
+};
 
 /// Represents the difference between two dates.
 #[derive(Debug, PartialEq, Eq)]
@@ -8,6 +13,52 @@ pub struct DateDifference {
     pub years: i64,
 }
 
+impl DateDifference {
+    /// Describe the difference in words, e.g. "1 year, 2 weeks, 5 days".
+    ///
+    /// The `days` total is broken down into whole years (365 days, matching how
+    /// [`date_difference`] computes `years`), then weeks, then remaining days.
+    /// Only `days` is read, so hand-built values with inconsistent `weeks` or
+    /// `years` fields are still described correctly. Zero components are skipped and
+    /// units are pluralized; a zero difference is "0 days". Negative differences
+    /// are described by their magnitude with a leading "-".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_utils_lib::date_difference;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
+    ///
+    /// assert_eq!(date_difference(&start, &end).describe(), "1 year, 2 weeks, 5 days");
+    /// assert_eq!(date_difference(&start, &start).describe(), "0 days");
+    /// ```
+    pub fn describe(&self) -> String {
+        let total = self.days.unsigned_abs();
+        let years = total / 365;
+        let remainder = total % 365;
+        let weeks = remainder / 7;
+        let days = remainder % 7;
+
+        let parts: Vec<String> = [(years, "year"), (weeks, "week"), (days, "day")]
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|&(count, unit)| {
+                format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+            })
+            .collect();
+
+        if parts.is_empty() {
+            return "0 days".to_string();
+        }
+
+        let sign = if self.days < 0 { "-" } else { "" };
+        format!("{}{}", sign, parts.join(", "))
+    }
+}
+
 /// Calculate the difference between two dates.
 ///
 /// Returns a `DateDifference` struct containing the difference in days, weeks, and years.
@@ -34,6 +85,392 @@ pub fn date_difference(date1: &NaiveDate, date2: &NaiveDate) -> DateDifference {
     DateDifference { days, weeks, years }
 }
 
+/// Count the completed calendar years between two dates.
+///
+/// Unlike the approximate `years` field of [`DateDifference`], this accounts for
+/// leap years: a year is complete once `end` reaches the same month and day as
+/// `start`. For a Feb 29 `start`, the anniversary in a non-leap year falls on
+/// Mar 1. If `end` is before `start`, the result is negative.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::full_years_between;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+/// assert_eq!(full_years_between(&start, &end), 3);
+///
+/// let birthday = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+/// let day_before = NaiveDate::from_ymd_opt(2001, 2, 28).unwrap();
+/// let day_after = NaiveDate::from_ymd_opt(2001, 3, 1).unwrap();
+/// assert_eq!(full_years_between(&birthday, &day_before), 0);
+/// assert_eq!(full_years_between(&birthday, &day_after), 1);
+/// ```
+pub fn full_years_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    if end < start {
+        return -full_years_between(end, start);
+    }
+
+    let mut years = (end.year() - start.year()) as i64;
+    if (end.month(), end.day()) < (start.month(), start.day()) {
+        years -= 1;
+    }
+    years
+}
+
+/// Count the days between two dates, including both endpoints.
+///
+/// A span where `start == end` counts as 1 day. If `end` is before `start`,
+/// the result is the negated inclusive count (e.g. -8 for a reversed one-week span).
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::inclusive_days_between;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+///
+/// assert_eq!(inclusive_days_between(&start, &end), 8);
+/// assert_eq!(inclusive_days_between(&start, &start), 1);
+/// assert_eq!(inclusive_days_between(&end, &start), -8);
+/// ```
+pub fn inclusive_days_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    let days = (*end - *start).num_days();
+    if days >= 0 { days + 1 } else { days - 1 }
+}
+
+/// Iterate over dates from `start` (inclusive) to `end` (exclusive) in steps of `step_days`.
+///
+/// Yields `start`, `start + step_days`, `start + 2 * step_days`, ... while the
+/// date is before `end`. The iterator is empty when `start >= end` or when
+/// `step_days` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::date_range_step;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
+///
+/// let weekly: Vec<NaiveDate> = date_range_step(start, end, 7).collect();
+/// assert_eq!(weekly.len(), 3); // Jan 1, 8, 15
+/// assert_eq!(date_range_step(start, end, 0).count(), 0);
+/// ```
+pub fn date_range_step(
+    start: NaiveDate,
+    end: NaiveDate,
+    step_days: i64,
+) -> impl Iterator<Item = NaiveDate> {
+    let step = if step_days > 0 {
+        TimeDelta::try_days(step_days)
+    } else {
+        None
+    };
+
+    std::iter::successors(step.map(|_| start), move |date| {
+        step.and_then(|step| date.checked_add_signed(step))
+    })
+    .take_while(move |date| *date < end)
+}
+
+/// List the first day of every month from `start`'s month through `end`'s month, inclusive.
+///
+/// Handles year boundaries. Returns an empty vector if `end` is in an earlier
+/// month than `start`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::month_starts;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 12, 15).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 2, 3).unwrap();
+///
+/// assert_eq!(
+///     month_starts(start, end),
+///     vec![
+///         NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn month_starts(start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    let mut months = Vec::new();
+    let mut current = start.with_day(1);
+
+    while let Some(month) = current {
+        if month > end {
+            break;
+        }
+        months.push(month);
+        current = month.checked_add_months(Months::new(1));
+    }
+    months
+}
+
+/// Return which week of its month a date falls in, from 1 to 6.
+///
+/// Weeks start on Monday: week 1 runs from the 1st up to the first Sunday,
+/// and each following Monday begins a new week. A month starting on a Sunday
+/// therefore has a one-day first week.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::week_of_month;
+/// use chrono::NaiveDate;
+///
+/// // September 2024 starts on a Sunday
+/// assert_eq!(week_of_month(&NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()), 1);
+/// assert_eq!(week_of_month(&NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()), 2);
+/// assert_eq!(week_of_month(&NaiveDate::from_ymd_opt(2024, 9, 30).unwrap()), 6);
+/// ```
+pub fn week_of_month(date: &NaiveDate) -> u32 {
+    // Day 1 always exists, so with_day(1) cannot fail
+    let first = date.with_day(1).unwrap_or(*date);
+    let offset = first.weekday().num_days_from_monday();
+    (date.day() - 1 + offset) / 7 + 1
+}
+
+/// Count the days from `from` until the next occurrence of `recurring`'s month and day.
+///
+/// The year of `recurring` is ignored, so this suits birthdays and
+/// anniversaries. Returns 0 when `from` falls on the occurrence itself. A
+/// Feb 29 date recurs on Mar 1 in non-leap years, matching
+/// [`full_years_between`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::days_until_next_occurrence;
+/// use chrono::NaiveDate;
+///
+/// let birthday = NaiveDate::from_ymd_opt(1990, 1, 5).unwrap();
+///
+/// let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// assert_eq!(days_until_next_occurrence(&birthday, &today), 4);
+///
+/// // Already passed this year, so count to next year's
+/// let today = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// assert_eq!(days_until_next_occurrence(&birthday, &today), 6);
+/// ```
+pub fn days_until_next_occurrence(recurring: &NaiveDate, from: &NaiveDate) -> i64 {
+    let occurrence_in = |year: i32| {
+        NaiveDate::from_ymd_opt(year, recurring.month(), recurring.day())
+            .or_else(|| NaiveDate::from_ymd_opt(year, 3, 1))
+    };
+
+    // Only runs out at the very end of chrono's supported range
+    [from.year(), from.year() + 1]
+        .into_iter()
+        .filter_map(occurrence_in)
+        .find(|date| date >= from)
+        .map_or(0, |date| (date - *from).num_days())
+}
+
+/// Parse a timestamp that may be either a full datetime or just a date.
+///
+/// Datetime formats are tried first: "YYYY-MM-DD HH:MM:SS" and
+/// "YYYY-MM-DDTHH:MM:SS" (both with optional fractional seconds), and
+/// "YYYY-MM-DD HH:MM". Otherwise the input is parsed as a date with
+/// [`parse_date`] and the time is set to midnight. Returns `None` if nothing
+/// matches.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::parse_any;
+/// use chrono::NaiveDate;
+///
+/// let midnight = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(parse_any("2024-12-25"), Some(midnight));
+/// assert_eq!(parse_any("25/12/2024"), Some(midnight));
+///
+/// let timestamp = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap().and_hms_opt(14, 30, 5).unwrap();
+/// assert_eq!(parse_any("2024-12-25T14:30:05"), Some(timestamp));
+///
+/// assert_eq!(parse_any("not a date"), None);
+/// ```
+pub fn parse_any(s: &str) -> Option<NaiveDateTime> {
+    let formats = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+    ];
+
+    for format in formats {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+            return Some(datetime);
+        }
+    }
+
+    parse_date(s).ok().map(|date| date.and_time(NaiveTime::MIN))
+}
+
+/// Count the business days (Monday to Friday) in `[start, end)`, skipping holidays.
+///
+/// `start` is included and `end` is not. A date in `holidays` is only
+/// subtracted if it is a weekday inside the range; duplicates are counted
+/// once. If `end` is before `start`, the result is the negated count for
+/// `[end, start)`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::business_days_between_excluding;
+/// use chrono::NaiveDate;
+///
+/// // Monday 2024-12-23 up to Monday 2024-12-30, with Christmas off
+/// let start = NaiveDate::from_ymd_opt(2024, 12, 23).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+/// let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+///
+/// assert_eq!(business_days_between_excluding(&start, &end, &[]), 5);
+/// assert_eq!(business_days_between_excluding(&start, &end, &[christmas]), 4);
+/// ```
+pub fn business_days_between_excluding(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    holidays: &[NaiveDate],
+) -> i64 {
+    if end < start {
+        return -business_days_between_excluding(end, start, holidays);
+    }
+
+    let is_weekday = |date: &NaiveDate| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+
+    // Whole weeks contribute 5 days each; walk the leftover days
+    let total_days = (*end - *start).num_days();
+    let mut count = total_days / 7 * 5;
+    let leftover_start = *end - TimeDelta::days(total_days % 7);
+    count += date_range_step(leftover_start, *end, 1)
+        .filter(|date| date < end && is_weekday(date))
+        .count() as i64;
+
+    let skipped: HashSet<&NaiveDate> = holidays
+        .iter()
+        .filter(|&day| day >= start && day < end && is_weekday(day))
+        .collect();
+    count - skipped.len() as i64
+}
+
+/// Describe a raw day count as approximate years, months, and days.
+///
+/// The breakdown is approximate: a year is always 365 days and a month is
+/// always 30 days. Zero components are skipped and units are pluralized; zero
+/// is "0 days". Negative counts are described by their magnitude with a
+/// leading "-", like [`DateDifference::describe`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::humanize_days;
+///
+/// assert_eq!(humanize_days(825), "2 years, 3 months, 5 days");
+/// assert_eq!(humanize_days(31), "1 month, 1 day");
+/// assert_eq!(humanize_days(0), "0 days");
+/// assert_eq!(humanize_days(-400), "-1 year, 1 month, 5 days");
+/// ```
+pub fn humanize_days(total_days: i64) -> String {
+    let total = total_days.unsigned_abs();
+    let years = total / 365;
+    let months = total % 365 / 30;
+    let days = total % 365 % 30;
+
+    let parts: Vec<String> = [(years, "year"), (months, "month"), (days, "day")]
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|&(count, unit)| format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" }))
+        .collect();
+
+    if parts.is_empty() {
+        return "0 days".to_string();
+    }
+
+    let sign = if total_days < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(", "))
+}
+
+/// Calculate the date of Easter Sunday in the Gregorian calendar.
+///
+/// Uses the Anonymous Gregorian (Meeus/Jones/Butcher) algorithm. Other
+/// movable holidays follow from it, e.g. Good Friday is two days earlier.
+///
+/// # Panics
+///
+/// Panics if `year` is outside the range supported by [`NaiveDate`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::easter_sunday;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(easter_sunday(2024), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+/// assert_eq!(easter_sunday(2025), NaiveDate::from_ymd_opt(2025, 4, 20).unwrap());
+/// ```
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    let y = year as i64;
+    let a = y.rem_euclid(19);
+    let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        .expect("year within NaiveDate's supported range")
+}
+
+/// Convert a naive datetime to a Unix timestamp in seconds.
+///
+/// The datetime carries no time zone, so it is interpreted as UTC. Dates
+/// before 1970-01-01 give negative timestamps.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use rust_utils_lib::to_unix_timestamp;
+///
+/// let dt = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(to_unix_timestamp(&dt), 1_704_067_200);
+/// ```
+pub fn to_unix_timestamp(dt: &NaiveDateTime) -> i64 {
+    dt.and_utc().timestamp()
+}
+
+/// Convert a Unix timestamp in seconds to a naive datetime in UTC.
+///
+/// Returns `None` if the timestamp is outside the range chrono can represent.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use rust_utils_lib::from_unix_timestamp;
+///
+/// let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(from_unix_timestamp(0), Some(epoch));
+/// assert_eq!(from_unix_timestamp(i64::MAX), None);
+/// ```
+pub fn from_unix_timestamp(secs: i64) -> Option<NaiveDateTime> {
+    DateTime::from_timestamp(secs, 0).map(|dt| dt.naive_utc())
+}
+
 /// Validate if a string matches a specific date format.
 ///
 /// Supported formats:
@@ -52,14 +489,86 @@ pub fn date_difference(date1: &NaiveDate, date2: &NaiveDate) -> DateDifference {
 /// assert!(!validate_date_format("invalid", "YYYY-MM-DD"));
 /// ```
 pub fn validate_date_format(date_str: &str, format: &str) -> bool {
-    let chrono_format = match format {
-        "DD/MM/YYYY" => "%d/%m/%Y",
-        "YYYY-MM-DD" => "%Y-%m-%d",
-        "MM/DD/YYYY" => "%m/%d/%Y",
-        _ => return false,
-    };
+    validate_and_parse(date_str, format).is_some()
+}
 
-    NaiveDate::parse_from_str(date_str, chrono_format).is_ok()
+/// Validate a date string against a specific format and return the parsed date.
+///
+/// Accepts the same formats as [`validate_date_format`], but returns the parsed
+/// `NaiveDate` so callers don't have to parse the string a second time.
+/// Returns `None` if the format is unknown or the string doesn't match it.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::validate_and_parse;
+/// use chrono::NaiveDate;
+///
+/// let date = validate_and_parse("25/12/2024", "DD/MM/YYYY");
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 12, 25));
+///
+/// assert_eq!(validate_and_parse("2024-12-25", "DD/MM/YYYY"), None);
+/// assert_eq!(validate_and_parse("25/12/2024", "INVALID"), None);
+/// ```
+pub fn validate_and_parse(date_str: &str, format: &str) -> Option<NaiveDate> {
+    let chrono_format = input_format(format)?;
+    NaiveDate::parse_from_str(date_str, chrono_format).ok()
+}
+
+/// Validate a date string against a format, explaining any failure.
+///
+/// Returns `Ok(())` when the string is a valid date in `format`. Otherwise the
+/// error is one of:
+/// - "unknown format" when `format` is not one of the supported formats
+/// - "does not match <format>" when the string has the wrong shape
+/// - "invalid day/month" when the shape is right but the date doesn't exist
+///
+/// Supports the same formats as [`validate_date_format`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::validate_date_detailed;
+///
+/// assert_eq!(validate_date_detailed("25/12/2024", "DD/MM/YYYY"), Ok(()));
+/// assert_eq!(
+///     validate_date_detailed("2024-12-25", "DD/MM/YYYY"),
+///     Err("does not match DD/MM/YYYY".to_string())
+/// );
+/// assert_eq!(
+///     validate_date_detailed("31/02/2024", "DD/MM/YYYY"),
+///     Err("invalid day/month".to_string())
+/// );
+/// assert_eq!(
+///     validate_date_detailed("25/12/2024", "DD.MM.YYYY"),
+///     Err("unknown format".to_string())
+/// );
+/// ```
+pub fn validate_date_detailed(date_str: &str, format: &str) -> Result<(), String> {
+    let chrono_format = input_format(format).ok_or_else(|| "unknown format".to_string())?;
+
+    match NaiveDate::parse_from_str(date_str, chrono_format) {
+        Ok(_) => Ok(()),
+        Err(e)
+            if matches!(
+                e.kind(),
+                ParseErrorKind::OutOfRange | ParseErrorKind::Impossible
+            ) =>
+        {
+            Err("invalid day/month".to_string())
+        }
+        Err(_) => Err(format!("does not match {}", format)),
+    }
+}
+
+/// Map a supported input format name to its chrono format string.
+fn input_format(format: &str) -> Option<&'static str> {
+    match format {
+        "DD/MM/YYYY" => Some("%d/%m/%Y"),
+        "YYYY-MM-DD" => Some("%Y-%m-%d"),
+        "MM/DD/YYYY" => Some("%m/%d/%Y"),
+        _ => None,
+    }
 }
 
 /// Format a date in different styles.
@@ -69,6 +578,7 @@ pub fn validate_date_format(date_str: &str, format: &str) -> bool {
 /// - "YYYY-MM-DD" (e.g., "2024-12-25")
 /// - "MM/DD/YYYY" (e.g., "12/25/2024")
 /// - "Month DD, YYYY" (e.g., "December 25, 2024")
+/// - "Month Do, YYYY" (e.g., "December 25th, 2024")
 ///
 /// # Examples
 ///
@@ -82,6 +592,7 @@ pub fn validate_date_format(date_str: &str, format: &str) -> bool {
 /// assert_eq!(format_date(&date, "YYYY-MM-DD"), Some("2024-12-25".to_string()));
 /// assert_eq!(format_date(&date, "MM/DD/YYYY"), Some("12/25/2024".to_string()));
 /// assert_eq!(format_date(&date, "Month DD, YYYY"), Some("December 25, 2024".to_string()));
+/// assert_eq!(format_date(&date, "Month Do, YYYY"), Some("December 25th, 2024".to_string()));
 /// assert_eq!(format_date(&date, "INVALID"), None);
 /// ```
 pub fn format_date(date: &NaiveDate, format: &str) -> Option<String> {
@@ -90,15 +601,40 @@ pub fn format_date(date: &NaiveDate, format: &str) -> Option<String> {
         "YYYY-MM-DD" => "%Y-%m-%d",
         "MM/DD/YYYY" => "%m/%d/%Y",
         "Month DD, YYYY" => "%B %d, %Y",
+        "Month Do, YYYY" => {
+            let day = date.day();
+            return Some(format!(
+                "{} {}{}, {}",
+                date.format("%B"),
+                day,
+                ordinal_suffix(day),
+                date.year()
+            ));
+        }
         _ => return None,
     };
 
     Some(date.format(chrono_format).to_string())
 }
 
+/// English ordinal suffix for a day of the month ("st", "nd", "rd" or "th").
+fn ordinal_suffix(day: u32) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
 /// Parse a date string in various common formats.
 ///
-/// Attempts to parse the date using multiple common formats.
+/// Attempts to parse the date using multiple common formats, in this order:
+/// "YYYY-MM-DD", "DD/MM/YYYY", "MM/DD/YYYY". Day and month may be written
+/// without leading zeros (e.g. "5/3/2024"). When a slash-separated date is
+/// valid in both orders, day-first wins, so "5/3/2024" is 5 March 2024;
+/// month-first is only used when day-first fails (e.g. "3/13/2024").
 ///
 /// # Examples
 ///
@@ -111,10 +647,41 @@ pub fn format_date(date: &NaiveDate, format: &str) -> Option<String> {
 ///
 /// let date = parse_date("25/12/2024").unwrap();
 /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 12, 25).unwrap());
+///
+/// // Leading zeros are optional; ambiguous dates are read day-first
+/// let date = parse_date("5/3/2024").unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
 /// ```
 pub fn parse_date(date_str: &str) -> Result<NaiveDate, ParseError> {
+    parse_date_ordered(date_str, true)
+}
+
+/// Parse a date string, choosing which slash-separated order to try first.
+///
+/// Like [`parse_date`], but `day_first` decides whether "DD/MM/YYYY" or
+/// "MM/DD/YYYY" is tried first, so ambiguous inputs such as "03/04/2024"
+/// resolve deterministically for the caller's locale. The other order is
+/// still used as a fallback when the preferred one fails.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::parse_date_ordered;
+/// use chrono::NaiveDate;
+///
+/// let date = parse_date_ordered("03/04/2024", true).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 4, 3).unwrap());
+///
+/// let date = parse_date_ordered("03/04/2024", false).unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+/// ```
+pub fn parse_date_ordered(date_str: &str, day_first: bool) -> Result<NaiveDate, ParseError> {
     // Try different formats
-    let formats = vec!["%Y-%m-%d", "%d/%m/%Y", "%m/%d/%Y"];
+    let formats = if day_first {
+        ["%Y-%m-%d", "%d/%m/%Y", "%m/%d/%Y"]
+    } else {
+        ["%Y-%m-%d", "%m/%d/%Y", "%d/%m/%Y"]
+    };
 
     for format in formats {
         if let Ok(date) = NaiveDate::parse_from_str(date_str, format) {
@@ -172,6 +739,586 @@ mod tests {
         assert_eq!(diff.years, 0);
     }
 
+    // Tests for DateDifference::describe
+    #[test]
+    fn test_describe_zero() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(date_difference(&date, &date).describe(), "0 days");
+    }
+
+    #[test]
+    fn test_describe_single_unit() {
+        let diff = DateDifference {
+            days: 1,
+            weeks: 0,
+            years: 0,
+        };
+        assert_eq!(diff.describe(), "1 day");
+
+        let diff = DateDifference {
+            days: 14,
+            weeks: 2,
+            years: 0,
+        };
+        assert_eq!(diff.describe(), "2 weeks");
+
+        let diff = DateDifference {
+            days: 365,
+            weeks: 52,
+            years: 1,
+        };
+        assert_eq!(diff.describe(), "1 year");
+    }
+
+    #[test]
+    fn test_describe_multiple_units() {
+        let diff = DateDifference {
+            days: 384,
+            weeks: 54,
+            years: 1,
+        };
+        assert_eq!(diff.describe(), "1 year, 2 weeks, 5 days");
+
+        let diff = DateDifference {
+            days: 738,
+            weeks: 105,
+            years: 2,
+        };
+        assert_eq!(diff.describe(), "2 years, 1 week, 1 day");
+    }
+
+    #[test]
+    fn test_describe_negative() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(date_difference(&start, &end).describe(), "-1 week, 2 days");
+    }
+
+    #[test]
+    fn test_describe_inconsistent_fields() {
+        // Only `days` is used; `weeks` and `years` are ignored
+        let diff = DateDifference {
+            days: 10,
+            weeks: 0,
+            years: 1,
+        };
+        assert_eq!(diff.describe(), "1 week, 3 days");
+
+        let diff = DateDifference {
+            days: -400,
+            weeks: 5,
+            years: 0,
+        };
+        assert_eq!(diff.describe(), "-1 year, 5 weeks");
+    }
+
+    // Tests for full_years_between
+    #[test]
+    fn test_full_years_leap_spanning_range() {
+        let start = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(full_years_between(&start, &end), 3);
+        // 1460 days / 365 overestimates a full year
+        assert_eq!(date_difference(&start, &end).years, 4);
+    }
+
+    #[test]
+    fn test_full_years_exact_anniversary() {
+        let start = NaiveDate::from_ymd_opt(2010, 6, 15).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        assert_eq!(full_years_between(&start, &end), 14);
+
+        let day_before = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        assert_eq!(full_years_between(&start, &day_before), 13);
+    }
+
+    #[test]
+    fn test_full_years_feb_29_start() {
+        let start = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+
+        let feb_28 = NaiveDate::from_ymd_opt(2021, 2, 28).unwrap();
+        let mar_1 = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+        let next_leap = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(full_years_between(&start, &feb_28), 0);
+        assert_eq!(full_years_between(&start, &mar_1), 1);
+        assert_eq!(full_years_between(&start, &next_leap), 4);
+    }
+
+    #[test]
+    fn test_full_years_reversed_and_same() {
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2005, 6, 1).unwrap();
+
+        assert_eq!(full_years_between(&end, &start), -5);
+        assert_eq!(full_years_between(&start, &start), 0);
+    }
+
+    // Tests for inclusive_days_between
+    #[test]
+    fn test_inclusive_days_same_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(inclusive_days_between(&date, &date), 1);
+    }
+
+    #[test]
+    fn test_inclusive_days_one_week() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        assert_eq!(inclusive_days_between(&start, &end), 8);
+    }
+
+    #[test]
+    fn test_inclusive_days_reversed() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        assert_eq!(inclusive_days_between(&end, &start), -8);
+
+        let next_day = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        assert_eq!(inclusive_days_between(&next_day, &start), -2);
+    }
+
+    // Tests for date_range_step
+    #[test]
+    fn test_date_range_step_weekly() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 29).unwrap();
+
+        let dates: Vec<NaiveDate> = date_range_step(start, end, 7).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 22).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_date_range_step_excludes_end() {
+        let start = NaiveDate::from_ymd_opt(2024, 2, 27).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+
+        let dates: Vec<NaiveDate> = date_range_step(start, end, 2).collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 2, 27).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_date_range_step_empty() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        assert_eq!(date_range_step(start, end, 1).count(), 0);
+        assert_eq!(date_range_step(start, start, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_date_range_step_non_positive() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+
+        assert_eq!(date_range_step(start, end, 0).count(), 0);
+        assert_eq!(date_range_step(start, end, -7).count(), 0);
+    }
+
+    // Tests for month_starts
+    #[test]
+    fn test_month_starts_across_year_boundary() {
+        let start = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+
+        assert_eq!(
+            month_starts(start, end),
+            vec![
+                NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_month_starts_single_month() {
+        let start = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+
+        assert_eq!(
+            month_starts(start, end),
+            vec![NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_month_starts_reversed() {
+        let start = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+
+        assert!(month_starts(start, end).is_empty());
+    }
+
+    // Tests for parse_date_ordered
+    #[test]
+    fn test_parse_date_ordered_day_first() {
+        let date = parse_date_ordered("03/04/2024", true).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 4, 3).unwrap());
+    }
+
+    #[test]
+    fn test_parse_date_ordered_month_first() {
+        let date = parse_date_ordered("03/04/2024", false).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+    }
+
+    #[test]
+    fn test_parse_date_ordered_fallback() {
+        // Only valid month-first, even when day-first is preferred
+        let date = parse_date_ordered("12/25/2024", true).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 12, 25).unwrap());
+
+        // ISO dates ignore the flag
+        let date = parse_date_ordered("2024-03-04", false).unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+
+        assert!(parse_date_ordered("not a date", false).is_err());
+    }
+
+    // Tests for week_of_month
+    #[test]
+    fn test_week_of_month_first_day() {
+        // July 2024 starts on a Monday, September 2024 on a Sunday
+        for (year, month) in [(2024, 7), (2024, 9), (2024, 2), (2025, 3)] {
+            let date = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+            assert_eq!(week_of_month(&date), 1);
+        }
+    }
+
+    #[test]
+    fn test_week_of_month_month_starting_monday() {
+        // July 2024: 1st is a Monday, 31st falls in week 5
+        assert_eq!(
+            week_of_month(&NaiveDate::from_ymd_opt(2024, 7, 7).unwrap()),
+            1
+        );
+        assert_eq!(
+            week_of_month(&NaiveDate::from_ymd_opt(2024, 7, 8).unwrap()),
+            2
+        );
+        assert_eq!(
+            week_of_month(&NaiveDate::from_ymd_opt(2024, 7, 31).unwrap()),
+            5
+        );
+    }
+
+    #[test]
+    fn test_week_of_month_month_starting_sunday() {
+        // September 2024: 1st is a Sunday, 30th falls in week 6
+        assert_eq!(
+            week_of_month(&NaiveDate::from_ymd_opt(2024, 9, 2).unwrap()),
+            2
+        );
+        assert_eq!(
+            week_of_month(&NaiveDate::from_ymd_opt(2024, 9, 30).unwrap()),
+            6
+        );
+    }
+
+    // Tests for validate_date_detailed
+    #[test]
+    fn test_validate_date_detailed_success() {
+        assert_eq!(validate_date_detailed("25/12/2024", "DD/MM/YYYY"), Ok(()));
+        assert_eq!(validate_date_detailed("2024-02-29", "YYYY-MM-DD"), Ok(()));
+        assert_eq!(validate_date_detailed("12/25/2024", "MM/DD/YYYY"), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_date_detailed_unknown_format() {
+        assert_eq!(
+            validate_date_detailed("25/12/2024", "INVALID"),
+            Err("unknown format".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_date_detailed_shape_mismatch() {
+        for input in ["2024-12-25", "25-12-2024", "hello", "", "25/12"] {
+            assert_eq!(
+                validate_date_detailed(input, "DD/MM/YYYY"),
+                Err("does not match DD/MM/YYYY".to_string()),
+                "input: {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_date_detailed_invalid_day_month() {
+        for input in ["31/02/2024", "29/02/2023", "32/01/2024", "15/13/2024"] {
+            assert_eq!(
+                validate_date_detailed(input, "DD/MM/YYYY"),
+                Err("invalid day/month".to_string()),
+                "input: {:?}",
+                input
+            );
+        }
+    }
+
+    // Tests for days_until_next_occurrence
+    #[test]
+    fn test_days_until_next_occurrence_same_day() {
+        let recurring = NaiveDate::from_ymd_opt(1985, 7, 4).unwrap();
+        let from = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+        assert_eq!(days_until_next_occurrence(&recurring, &from), 0);
+    }
+
+    #[test]
+    fn test_days_until_next_occurrence_later_this_year() {
+        let recurring = NaiveDate::from_ymd_opt(1985, 7, 4).unwrap();
+        let from = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        assert_eq!(days_until_next_occurrence(&recurring, &from), 3);
+    }
+
+    #[test]
+    fn test_days_until_next_occurrence_across_year_boundary() {
+        let recurring = NaiveDate::from_ymd_opt(2000, 1, 2).unwrap();
+        let from = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        assert_eq!(days_until_next_occurrence(&recurring, &from), 2);
+
+        // The day after the occurrence waits almost a full year
+        let from = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+        assert_eq!(days_until_next_occurrence(&recurring, &from), 364);
+    }
+
+    #[test]
+    fn test_days_until_next_occurrence_feb_29() {
+        let leap_day = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+
+        // Non-leap year: recurs on Mar 1
+        let from = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+        assert_eq!(days_until_next_occurrence(&leap_day, &from), 1);
+
+        // Leap year: recurs on Feb 29 itself
+        let from = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        assert_eq!(days_until_next_occurrence(&leap_day, &from), 1);
+        let from = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(days_until_next_occurrence(&leap_day, &from), 0);
+    }
+
+    // Tests for parse_any
+    #[test]
+    fn test_parse_any_date_only_is_midnight() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 5)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(parse_any("2024-03-05"), Some(expected));
+        assert_eq!(parse_any("05/03/2024"), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_any_full_timestamp() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(
+            parse_any("2024-03-05 08:15:30"),
+            date.and_hms_opt(8, 15, 30)
+        );
+        assert_eq!(
+            parse_any("2024-03-05T08:15:30"),
+            date.and_hms_opt(8, 15, 30)
+        );
+        assert_eq!(parse_any("2024-03-05 08:15"), date.and_hms_opt(8, 15, 0));
+    }
+
+    #[test]
+    fn test_parse_any_fractional_seconds() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(
+            parse_any("2024-03-05T08:15:30.250"),
+            date.and_hms_milli_opt(8, 15, 30, 250)
+        );
+    }
+
+    #[test]
+    fn test_parse_any_invalid() {
+        assert_eq!(parse_any(""), None);
+        assert_eq!(parse_any("2024-03-05 25:00:00"), None);
+        assert_eq!(parse_any("yesterday"), None);
+    }
+
+    // Tests for business_days_between_excluding
+    #[test]
+    fn test_business_days_excluding_weekday_holiday() {
+        // Mon 2024-07-01 to Mon 2024-07-08; July 4th is a Thursday
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+        let july_4 = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+
+        assert_eq!(business_days_between_excluding(&start, &end, &[]), 5);
+        assert_eq!(business_days_between_excluding(&start, &end, &[july_4]), 4);
+    }
+
+    #[test]
+    fn test_business_days_excluding_weekend_holiday() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+        // Saturday: no effect
+        let saturday = NaiveDate::from_ymd_opt(2024, 7, 6).unwrap();
+        assert_eq!(
+            business_days_between_excluding(&start, &end, &[saturday]),
+            5
+        );
+    }
+
+    #[test]
+    fn test_business_days_excluding_duplicates_and_out_of_range() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 8).unwrap();
+        let july_4 = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+        // `end` itself is excluded from the range
+        let outside = [end, NaiveDate::from_ymd_opt(2024, 6, 28).unwrap()];
+
+        assert_eq!(
+            business_days_between_excluding(&start, &end, &[july_4, july_4]),
+            4
+        );
+        assert_eq!(business_days_between_excluding(&start, &end, &outside), 5);
+    }
+
+    #[test]
+    fn test_business_days_excluding_partial_weeks() {
+        // Fri 2024-07-05 to Tue 2024-07-09: Fri and Mon
+        let start = NaiveDate::from_ymd_opt(2024, 7, 5).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 9).unwrap();
+        assert_eq!(business_days_between_excluding(&start, &end, &[]), 2);
+        assert_eq!(business_days_between_excluding(&end, &start, &[]), -2);
+        assert_eq!(business_days_between_excluding(&start, &start, &[]), 0);
+    }
+
+    #[test]
+    fn test_business_days_excluding_matches_day_by_day_count() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        for len in 0..40 {
+            let end = start + TimeDelta::days(len);
+            let expected = date_range_step(start, end, 1)
+                .filter(|d| *d < end && d.weekday().num_days_from_monday() < 5)
+                .count() as i64;
+            assert_eq!(business_days_between_excluding(&start, &end, &[]), expected);
+        }
+    }
+
+    // Tests for humanize_days
+    #[test]
+    fn test_humanize_days_zero() {
+        assert_eq!(humanize_days(0), "0 days");
+    }
+
+    #[test]
+    fn test_humanize_days_few_days() {
+        assert_eq!(humanize_days(1), "1 day");
+        assert_eq!(humanize_days(5), "5 days");
+        assert_eq!(humanize_days(30), "1 month");
+        assert_eq!(humanize_days(64), "2 months, 4 days");
+    }
+
+    #[test]
+    fn test_humanize_days_exact_year() {
+        assert_eq!(humanize_days(365), "1 year");
+        assert_eq!(humanize_days(730), "2 years");
+        assert_eq!(humanize_days(825), "2 years, 3 months, 5 days");
+    }
+
+    #[test]
+    fn test_humanize_days_negative() {
+        assert_eq!(humanize_days(-1), "-1 day");
+        assert_eq!(humanize_days(-365), "-1 year");
+        assert!(humanize_days(i64::MIN).starts_with('-'));
+    }
+
+    // Tests for easter_sunday
+    #[test]
+    fn test_easter_sunday_known_dates() {
+        let known = [
+            (2000, 4, 23),
+            (2019, 4, 21),
+            (2024, 3, 31),
+            (2025, 4, 20),
+            (2038, 4, 25),
+            (1818, 3, 22),
+        ];
+        for (year, month, day) in known {
+            assert_eq!(
+                easter_sunday(year),
+                NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_easter_sunday_is_always_sunday_in_range() {
+        for year in 1583..2500 {
+            let easter = easter_sunday(year);
+            assert_eq!(easter.weekday(), Weekday::Sun);
+            let earliest = NaiveDate::from_ymd_opt(year, 3, 22).unwrap();
+            let latest = NaiveDate::from_ymd_opt(year, 4, 25).unwrap();
+            assert!(easter >= earliest && easter <= latest);
+        }
+    }
+
+    // Tests for to_unix_timestamp and from_unix_timestamp
+    #[test]
+    fn test_unix_timestamp_round_trip_near_epoch() {
+        for secs in [-86_400, -1, 0, 1, 86_400] {
+            let dt = from_unix_timestamp(secs).unwrap();
+            assert_eq!(to_unix_timestamp(&dt), secs);
+        }
+
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(from_unix_timestamp(0), Some(epoch));
+    }
+
+    #[test]
+    fn test_unix_timestamp_known_recent() {
+        let dt = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(12, 30, 45)
+            .unwrap();
+        assert_eq!(to_unix_timestamp(&dt), 1_709_209_845);
+        assert_eq!(from_unix_timestamp(1_709_209_845), Some(dt));
+    }
+
+    #[test]
+    fn test_unix_timestamp_before_epoch() {
+        let dt = NaiveDate::from_ymd_opt(1969, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        assert_eq!(to_unix_timestamp(&dt), -1);
+
+        let dt = NaiveDate::from_ymd_opt(1900, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(to_unix_timestamp(&dt), -2_208_988_800);
+        assert_eq!(from_unix_timestamp(-2_208_988_800), Some(dt));
+    }
+
+    #[test]
+    fn test_from_unix_timestamp_out_of_range() {
+        assert_eq!(from_unix_timestamp(i64::MAX), None);
+        assert_eq!(from_unix_timestamp(i64::MIN), None);
+    }
+
     // Tests for validate_date_format
     #[test]
     fn test_validate_date_format_dd_mm_yyyy() {
@@ -202,6 +1349,34 @@ mod tests {
         assert!(!validate_date_format("invalid", "DD/MM/YYYY"));
     }
 
+    // Tests for validate_and_parse
+    #[test]
+    fn test_validate_and_parse_valid() {
+        assert_eq!(
+            validate_and_parse("25/12/2024", "DD/MM/YYYY"),
+            NaiveDate::from_ymd_opt(2024, 12, 25)
+        );
+        assert_eq!(
+            validate_and_parse("2024-12-25", "YYYY-MM-DD"),
+            NaiveDate::from_ymd_opt(2024, 12, 25)
+        );
+        assert_eq!(
+            validate_and_parse("12/25/2024", "MM/DD/YYYY"),
+            NaiveDate::from_ymd_opt(2024, 12, 25)
+        );
+    }
+
+    #[test]
+    fn test_validate_and_parse_invalid_date() {
+        assert_eq!(validate_and_parse("32/13/2024", "DD/MM/YYYY"), None);
+        assert_eq!(validate_and_parse("2024-12-25", "DD/MM/YYYY"), None);
+    }
+
+    #[test]
+    fn test_validate_and_parse_unknown_format() {
+        assert_eq!(validate_and_parse("25/12/2024", "INVALID"), None);
+    }
+
     // Tests for format_date
     #[test]
     fn test_format_date_dd_mm_yyyy() {
@@ -239,6 +1414,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_date_ordinal_day() {
+        let cases = [
+            (1, "December 1st, 2024"),
+            (2, "December 2nd, 2024"),
+            (3, "December 3rd, 2024"),
+            (11, "December 11th, 2024"),
+            (12, "December 12th, 2024"),
+            (13, "December 13th, 2024"),
+            (21, "December 21st, 2024"),
+            (22, "December 22nd, 2024"),
+            (25, "December 25th, 2024"),
+            (31, "December 31st, 2024"),
+        ];
+        for (day, expected) in cases {
+            let date = NaiveDate::from_ymd_opt(2024, 12, day).unwrap();
+            assert_eq!(
+                format_date(&date, "Month Do, YYYY"),
+                Some(expected.to_string())
+            );
+        }
+    }
+
     #[test]
     fn test_format_date_invalid_format() {
         let date = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
@@ -264,6 +1462,31 @@ mod tests {
         assert_eq!(result, NaiveDate::from_ymd_opt(2024, 12, 25).unwrap());
     }
 
+    #[test]
+    fn test_parse_date_single_digit_day_first() {
+        let result = parse_date("5/3/2024").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+
+        let result = parse_date("25/3/2024").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 25).unwrap());
+    }
+
+    #[test]
+    fn test_parse_date_single_digit_month_first() {
+        // Day 13 can't be a month, so month-first is used
+        let result = parse_date("3/13/2024").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 13).unwrap());
+
+        let result = parse_date("12/5/2024").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 12).unwrap());
+    }
+
+    #[test]
+    fn test_parse_date_single_digit_iso() {
+        let result = parse_date("2024-3-5").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+    }
+
     #[test]
     fn test_parse_date_invalid() {
         assert!(parse_date("invalid").is_err());
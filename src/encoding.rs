@@ -0,0 +1,77 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Encode bytes as Base64 using the standard alphabet with `=` padding.
+///
+/// Requires the `base64` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::to_base64;
+///
+/// assert_eq!(to_base64(b"hello"), "aGVsbG8=");
+/// assert_eq!(to_base64(b""), "");
+/// ```
+pub fn to_base64(data: &[u8]) -> String {
+    STANDARD.encode(data)
+}
+
+/// Decode a Base64 string using the standard alphabet with `=` padding.
+///
+/// Returns `None` if the input contains characters outside the alphabet or is
+/// not correctly padded. Requires the `base64` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::from_base64;
+///
+/// assert_eq!(from_base64("aGVsbG8="), Some(b"hello".to_vec()));
+/// assert_eq!(from_base64("not base64!"), None);
+/// ```
+pub fn from_base64(s: &str) -> Option<Vec<u8>> {
+    STANDARD.decode(s).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_empty() {
+        assert_eq!(to_base64(b""), "");
+        assert_eq!(from_base64(""), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_base64_no_padding() {
+        assert_eq!(to_base64(b"abc"), "YWJj");
+        assert_eq!(from_base64("YWJj"), Some(b"abc".to_vec()));
+    }
+
+    #[test]
+    fn test_base64_one_padding_char() {
+        assert_eq!(to_base64(b"ab"), "YWI=");
+        assert_eq!(from_base64("YWI="), Some(b"ab".to_vec()));
+    }
+
+    #[test]
+    fn test_base64_two_padding_chars() {
+        assert_eq!(to_base64(b"a"), "YQ==");
+        assert_eq!(from_base64("YQ=="), Some(b"a".to_vec()));
+    }
+
+    #[test]
+    fn test_base64_round_trip_binary() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(from_base64(&to_base64(&data)), Some(data));
+    }
+
+    #[test]
+    fn test_base64_invalid_input() {
+        assert_eq!(from_base64("YW*j"), None);
+        assert_eq!(from_base64("YQ"), None); // Missing padding
+        assert_eq!(from_base64("YQ==="), None);
+    }
+}
@@ -1,12 +1,14 @@
-use std::fs::{File, OpenOptions};
-use std::io::{self, Read, Write};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::Path;
+use std::time::SystemTime;
 
 /// Read the contents of a file and return it as a string.
 ///
 /// # Errors
 ///
 /// Returns an error if the file doesn't exist, can't be read, or contains invalid UTF-8.
+/// If `path` is a directory, the error has kind [`io::ErrorKind::InvalidInput`].
 ///
 /// # Examples
 ///
@@ -20,11 +22,313 @@ use std::path::Path;
 /// ```
 pub fn read_file<P: AsRef<Path>>(path: P) -> io::Result<String> {
     let mut file = File::open(path)?;
+    if file.metadata()?.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "path is a directory, not a file",
+        ));
+    }
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
     Ok(contents)
 }
 
+/// Read the contents of a file, returning `None` if the file doesn't exist.
+///
+/// Unlike [`read_file`], a missing file is not treated as an error. Any other
+/// IO error (permissions, invalid UTF-8, ...) is still returned as `Err`.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but can't be read or contains invalid UTF-8.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_file_optional;
+///
+/// match read_file_optional("config.txt") {
+///     Ok(Some(contents)) => println!("Config: {}", contents),
+///     Ok(None) => println!("No config file, using defaults"),
+///     Err(e) => eprintln!("Error reading config: {}", e),
+/// }
+/// ```
+pub fn read_file_optional<P: AsRef<Path>>(path: P) -> io::Result<Option<String>> {
+    match read_file(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Read the contents of a file, falling back to a default string on any error.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_file_or_default;
+///
+/// let config = read_file_or_default("config.toml", "debug = false");
+/// println!("Using config: {}", config);
+/// ```
+pub fn read_file_or_default<P: AsRef<Path>>(path: P, default: &str) -> String {
+    read_file(path).unwrap_or_else(|_| default.to_string())
+}
+
+/// Read the contents of a file, stripping a leading UTF-8 byte order mark.
+///
+/// Files saved by some Windows tools start with the BOM bytes `EF BB BF`.
+/// If present, it is removed; the rest of the content, and files without a
+/// BOM, are returned unchanged.
+///
+/// # Errors
+///
+/// Returns the same errors as [`read_file`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_file_no_bom;
+///
+/// let csv = read_file_no_bom("export.csv").unwrap();
+/// assert!(!csv.starts_with('\u{FEFF}'));
+/// ```
+pub fn read_file_no_bom<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let contents = read_file(path)?;
+    match contents.strip_prefix('\u{FEFF}') {
+        Some(stripped) => Ok(stripped.to_string()),
+        None => Ok(contents),
+    }
+}
+
+/// Read a file and prefix each line with its 1-based line number.
+///
+/// Line numbers are right-aligned to the width of the largest one and followed
+/// by `" | "`, so a 12-line file produces `" 1 | ..."` through `"12 | ..."`.
+/// Every output line ends with `'\n'`; an empty file produces an empty string.
+///
+/// # Errors
+///
+/// Returns an error if the file doesn't exist, can't be read, or contains invalid UTF-8.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_file_numbered;
+///
+/// let listing = read_file_numbered("main.rs").unwrap();
+/// print!("{}", listing);
+/// ```
+pub fn read_file_numbered<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let contents = read_file(path)?;
+    let lines: Vec<&str> = contents.lines().collect();
+    let width = lines.len().to_string().len();
+
+    let mut numbered = String::with_capacity(contents.len() + lines.len() * (width + 4));
+    for (index, line) in lines.iter().enumerate() {
+        numbered.push_str(&format!("{:>width$} | {}\n", index + 1, line));
+    }
+    Ok(numbered)
+}
+
+/// Find the lines of a file that contain a substring.
+///
+/// The file is streamed line by line rather than loaded into memory. Returns
+/// `(line_number, line)` pairs, with 1-based line numbers and line endings removed.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be opened or read, or contains invalid UTF-8.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::grep_file;
+///
+/// for (line_number, line) in grep_file("app.log", "ERROR").unwrap() {
+///     println!("{}: {}", line_number, line);
+/// }
+/// ```
+pub fn grep_file<P: AsRef<Path>>(path: P, pattern: &str) -> io::Result<Vec<(usize, String)>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut matches = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.contains(pattern) {
+            matches.push((index + 1, line));
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Read a file in fixed-size chunks, passing each chunk to a callback.
+///
+/// Every chunk holds exactly `chunk_size` bytes except possibly the last,
+/// which holds whatever remains. Only one chunk is kept in memory at a time,
+/// so this works for files too large to load at once. An empty file produces
+/// no chunks.
+///
+/// # Errors
+///
+/// Returns an error if `chunk_size` is 0 (with kind
+/// [`io::ErrorKind::InvalidInput`]), or if the file can't be opened or read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_file_chunks;
+///
+/// let mut total = 0;
+/// read_file_chunks("large.bin", 64 * 1024, |chunk| total += chunk.len()).unwrap();
+/// println!("read {} bytes", total);
+/// ```
+pub fn read_file_chunks<P, F>(path: P, chunk_size: usize, mut f: F) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    F: FnMut(&[u8]),
+{
+    if chunk_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "chunk size must be greater than zero",
+        ));
+    }
+
+    let mut file = File::open(path)?;
+    let mut buffer = vec![0; chunk_size];
+    loop {
+        // A single read may return less than asked for, so keep filling
+        let mut filled = 0;
+        while filled < chunk_size {
+            match file.read(&mut buffer[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        if filled == 0 {
+            return Ok(());
+        }
+        f(&buffer[..filled]);
+        if filled < chunk_size {
+            return Ok(());
+        }
+    }
+}
+
+/// A differing line reported by [`diff_lines`]: the 1-based line number and
+/// the left and right line, `None` where that file has no such line.
+pub type LineDiff = (usize, Option<String>, Option<String>);
+
+/// Compare two files line by line and return the lines that differ.
+///
+/// Each entry is `(line_number, left, right)` with a 1-based line number.
+/// Lines are compared by position only (no alignment of inserted or removed
+/// lines). When one file is shorter, its side is `None` for the extra lines.
+/// Identical files produce an empty vector. Both files are streamed rather
+/// than loaded into memory.
+///
+/// # Errors
+///
+/// Returns an error if either file can't be opened or read, or contains invalid UTF-8.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::diff_lines;
+///
+/// for (line, left, right) in diff_lines("old.txt", "new.txt").unwrap() {
+///     println!("{}: {:?} -> {:?}", line, left, right);
+/// }
+/// ```
+pub fn diff_lines<P: AsRef<Path>, Q: AsRef<Path>>(a: P, b: Q) -> io::Result<Vec<LineDiff>> {
+    let mut left = BufReader::new(File::open(a)?).lines();
+    let mut right = BufReader::new(File::open(b)?).lines();
+    let mut differences = Vec::new();
+
+    for line_number in 1.. {
+        let left_line = left.next().transpose()?;
+        let right_line = right.next().transpose()?;
+        if left_line.is_none() && right_line.is_none() {
+            break;
+        }
+        if left_line != right_line {
+            differences.push((line_number, left_line, right_line));
+        }
+    }
+
+    Ok(differences)
+}
+
+/// Read the last line of a file without loading the whole file.
+///
+/// Reads backward from the end in fixed-size blocks until a newline is
+/// found, so the cost depends on the length of the last line rather than the
+/// size of the file. A trailing newline (`\n` or `\r\n`) does not start a new
+/// empty line. Returns `None` for an empty file.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be opened or read, or if the last line
+/// is not valid UTF-8 (with kind [`io::ErrorKind::InvalidData`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::read_last_line;
+///
+/// if let Some(line) = read_last_line("app.log").unwrap() {
+///     println!("latest entry: {}", line);
+/// }
+/// ```
+pub fn read_last_line<P: AsRef<Path>>(path: P) -> io::Result<Option<String>> {
+    const BLOCK_SIZE: u64 = 4096;
+
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(None);
+    }
+
+    // Blocks are collected from the end backward and joined at the end
+    let mut blocks: Vec<Vec<u8>> = Vec::new();
+    let mut end = len;
+    let mut skip_trailing_newline = true;
+    'search: while end > 0 {
+        let start = end.saturating_sub(BLOCK_SIZE);
+        let mut block = vec![0; (end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut block)?;
+
+        if skip_trailing_newline {
+            skip_trailing_newline = false;
+            if block.last() == Some(&b'\n') {
+                block.pop();
+            }
+        }
+
+        if let Some(newline) = block.iter().rposition(|&b| b == b'\n') {
+            blocks.push(block.split_off(newline + 1));
+            break 'search;
+        }
+        blocks.push(block);
+        end = start;
+    }
+
+    let mut line: Vec<u8> = blocks.into_iter().rev().flatten().collect();
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 /// Write a string to a file, creating the file if it doesn't exist or overwriting it if it does.
 ///
 /// # Errors
@@ -48,6 +352,100 @@ pub fn write_file<P: AsRef<Path>>(path: P, content: &str) -> io::Result<()> {
     Ok(())
 }
 
+/// Write a string to a file, creating any missing parent directories first.
+///
+/// Behaves like [`write_file`] once the parent directories exist.
+///
+/// # Errors
+///
+/// Returns an error if a parent directory cannot be created, or the file
+/// cannot be created or written to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::write_file_mkdir;
+///
+/// write_file_mkdir("logs/2024/12/app.log", "started\n").unwrap();
+/// ```
+pub fn write_file_mkdir<P: AsRef<Path>>(path: P, content: &str) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_file(path, content)
+}
+
+/// Write a string to a file only if its contents would change.
+///
+/// The existing file, if any, is compared byte for byte with `content`.
+/// Returns `Ok(true)` if the file was created or rewritten and `Ok(false)` if
+/// it already held exactly `content`, leaving its modification time alone so
+/// build tools and file watchers aren't triggered.
+///
+/// # Errors
+///
+/// Returns an error if the existing file can't be read (other than not
+/// existing), or the file cannot be created or written to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::write_file_if_changed;
+///
+/// if write_file_if_changed("generated.rs", "pub const VERSION: u32 = 2;\n").unwrap() {
+///     println!("regenerated");
+/// }
+/// ```
+pub fn write_file_if_changed<P: AsRef<Path>>(path: P, content: &str) -> io::Result<bool> {
+    let path = path.as_ref();
+    match fs::read(path) {
+        Ok(existing) if existing == content.as_bytes() => return Ok(false),
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    write_file(path, content)?;
+    Ok(true)
+}
+
+/// Write a string to a file, creating it with the given Unix permission mode.
+///
+/// The mode (e.g. `0o600`) is applied atomically when the file is created, so
+/// the contents are never readable with looser permissions. It is subject to
+/// the process umask and does not change the permissions of an existing file.
+/// On non-Unix platforms `mode` is ignored and this behaves like [`write_file`].
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be created or written to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::write_file_with_mode;
+///
+/// // Only the owner can read or write the secret
+/// write_file_with_mode("secret.txt", "api-key", 0o600).unwrap();
+/// ```
+pub fn write_file_with_mode<P: AsRef<Path>>(path: P, content: &str, mode: u32) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(mode);
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+
+    let mut file = options.open(path)?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
 /// Append content to an existing file, creating it if it doesn't exist.
 ///
 /// # Errors
@@ -70,6 +468,88 @@ pub fn append_to_file<P: AsRef<Path>>(path: P, content: &str) -> io::Result<()>
     Ok(())
 }
 
+/// Append content to a file and return the number of bytes written.
+///
+/// When `ensure_newline` is `true` and `content` does not already end with `'\n'`,
+/// a trailing newline is appended as well and included in the returned count.
+/// The file is created if it doesn't exist.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or written to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::append_to_file_counted;
+///
+/// let written = append_to_file_counted("log.txt", "New log entry", true).unwrap();
+/// assert_eq!(written, 14); // 13 bytes of content + 1 newline
+/// ```
+pub fn append_to_file_counted<P: AsRef<Path>>(
+    path: P,
+    content: &str,
+    ensure_newline: bool,
+) -> io::Result<usize> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(content.as_bytes())?;
+
+    let mut written = content.len();
+    if ensure_newline && !content.ends_with('\n') {
+        file.write_all(b"\n")?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Append content to a file while holding an exclusive OS-level file lock.
+///
+/// Concurrent callers (threads or processes) using this function take turns, so
+/// each append lands as one contiguous block. The file is created if it doesn't
+/// exist. Requires the `filelock` feature.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened, locked, or written to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::append_to_file_locked;
+///
+/// append_to_file_locked("shared.log", "worker 1 finished\n").unwrap();
+/// ```
+#[cfg(feature = "filelock")]
+pub fn append_to_file_locked<P: AsRef<Path>>(path: P, content: &str) -> io::Result<()> {
+    use fs2::FileExt;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    FileExt::lock_exclusive(&file)?;
+    let result = file.write_all(content.as_bytes());
+    FileExt::unlock(&file)?;
+    result
+}
+
+/// Create an empty file if it doesn't exist, or update its modification time to now.
+///
+/// Like the Unix `touch` command, the contents of an existing file are left untouched.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be created or its timestamp cannot be updated.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rust_utils_lib::touch;
+///
+/// touch("build.stamp").unwrap();
+/// ```
+pub fn touch<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.set_modified(SystemTime::now())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +700,629 @@ mod tests {
 
         cleanup_file(path);
     }
+
+    #[test]
+    fn test_append_counted_matches_file_growth() {
+        let path = "test_append_counted_growth.txt";
+        write_file(path, "Header\n").unwrap();
+        let before = fs::metadata(path).unwrap().len();
+
+        let written = append_to_file_counted(path, "Line 1\n", false).unwrap();
+        let after = fs::metadata(path).unwrap().len();
+
+        assert_eq!(written, 7);
+        assert_eq!(after - before, written as u64);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_append_counted_adds_missing_newline() {
+        let path = "test_append_counted_newline.txt";
+        cleanup_file(path);
+
+        let written = append_to_file_counted(path, "no newline", true).unwrap();
+        assert_eq!(written, 11);
+        assert_eq!(read_file(path).unwrap(), "no newline\n");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_append_counted_keeps_existing_newline() {
+        let path = "test_append_counted_existing_newline.txt";
+        cleanup_file(path);
+
+        let written = append_to_file_counted(path, "has newline\n", true).unwrap();
+        assert_eq!(written, 12);
+        assert_eq!(read_file(path).unwrap(), "has newline\n");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_append_counted_without_newline_mode() {
+        let path = "test_append_counted_raw.txt";
+        cleanup_file(path);
+
+        let written = append_to_file_counted(path, "raw", false).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(read_file(path).unwrap(), "raw");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_optional_present_file() {
+        let path = "test_read_optional_present.txt";
+        write_file(path, "present").unwrap();
+
+        let result = read_file_optional(path).unwrap();
+        assert_eq!(result, Some("present".to_string()));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_optional_missing_file() {
+        let path = "test_read_optional_missing_12345.txt";
+        cleanup_file(path);
+
+        let result = read_file_optional(path).unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_read_file_directory_is_invalid_input() {
+        let path = "test_read_file_dir";
+        fs::create_dir_all(path).unwrap();
+
+        let err = read_file(path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "path is a directory, not a file");
+
+        let _ = fs::remove_dir(path);
+    }
+
+    #[test]
+    fn test_write_file_mkdir_creates_parents() {
+        let root = "test_write_mkdir_dir";
+        let _ = fs::remove_dir_all(root);
+        let path = format!("{}/a/b/c/out.txt", root);
+
+        write_file_mkdir(&path, "nested").unwrap();
+        assert!(Path::new(&format!("{}/a/b/c", root)).is_dir());
+        assert_eq!(read_file(&path).unwrap(), "nested");
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_write_file_mkdir_existing_parent() {
+        let path = "test_write_mkdir_flat.txt";
+        write_file_mkdir(path, "flat").unwrap();
+        assert_eq!(read_file(path).unwrap(), "flat");
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_optional_directory_is_error() {
+        let path = "test_read_optional_dir";
+        fs::create_dir_all(path).unwrap();
+
+        let err = read_file_optional(path).unwrap_err();
+        assert_ne!(err.kind(), io::ErrorKind::NotFound);
+
+        let _ = fs::remove_dir(path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_optional_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = "test_read_optional_no_perms.txt";
+        write_file(path, "secret").unwrap();
+        fs::set_permissions(path, fs::Permissions::from_mode(0o000)).unwrap();
+
+        // Privileged users (e.g. root in CI containers) bypass permission
+        // checks, so there is nothing to test; skip rather than pass silently
+        if fs::read_to_string(path).is_ok() {
+            cleanup_file(path);
+            eprintln!("skipping test_read_optional_permission_denied: running with privileges");
+            return;
+        }
+
+        let err = read_file_optional(path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_optional_invalid_utf8_is_error() {
+        let path = "test_read_optional_invalid_utf8.txt";
+        fs::write(path, [0x66, 0x6f, 0xff, 0xfe]).unwrap();
+
+        // Errors other than NotFound propagate instead of becoming None
+        let err = read_file_optional(path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_grep_file_matches() {
+        let path = "test_grep_matches.txt";
+        write_file(path, "alpha\nbeta\ngamma alpha\ndelta\n").unwrap();
+
+        let result = grep_file(path, "alpha").unwrap();
+        assert_eq!(
+            result,
+            vec![(1, "alpha".to_string()), (3, "gamma alpha".to_string())]
+        );
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_grep_file_no_matches() {
+        let path = "test_grep_no_matches.txt";
+        write_file(path, "one\ntwo\nthree").unwrap();
+
+        let result = grep_file(path, "four").unwrap();
+        assert!(result.is_empty());
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_grep_file_crlf_line_endings() {
+        let path = "test_grep_crlf.txt";
+        write_file(path, "first\r\nsecond\r\n").unwrap();
+
+        let result = grep_file(path, "second").unwrap();
+        assert_eq!(result, vec![(2, "second".to_string())]);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_grep_nonexistent_file() {
+        let path = "nonexistent_grep_file_12345.txt";
+        cleanup_file(path);
+
+        assert!(grep_file(path, "anything").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_with_mode_sets_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = "test_write_mode.txt";
+        cleanup_file(path);
+
+        write_file_with_mode(path, "secret", 0o600).unwrap();
+
+        let mode = fs::metadata(path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(read_file(path).unwrap(), "secret");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_write_file_with_mode_overwrites() {
+        let path = "test_write_mode_overwrite.txt";
+        cleanup_file(path);
+
+        write_file_with_mode(path, "first version", 0o644).unwrap();
+        write_file_with_mode(path, "second", 0o644).unwrap();
+        assert_eq!(read_file(path).unwrap(), "second");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_or_default_present_file() {
+        let path = "test_read_or_default_present.txt";
+        write_file(path, "from file").unwrap();
+
+        assert_eq!(read_file_or_default(path, "fallback"), "from file");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_or_default_missing_file() {
+        let path = "test_read_or_default_missing_12345.txt";
+        cleanup_file(path);
+
+        assert_eq!(read_file_or_default(path, "fallback"), "fallback");
+    }
+
+    #[test]
+    fn test_read_or_default_empty_file_is_not_default() {
+        let path = "test_read_or_default_empty.txt";
+        write_file(path, "").unwrap();
+
+        assert_eq!(read_file_or_default(path, "fallback"), "");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_touch_creates_missing_file() {
+        let path = "test_touch_create.txt";
+        cleanup_file(path);
+
+        touch(path).unwrap();
+
+        assert!(fs::metadata(path).is_ok());
+        assert_eq!(read_file(path).unwrap(), "");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_touch_preserves_contents() {
+        let path = "test_touch_preserve.txt";
+        write_file(path, "keep me").unwrap();
+
+        touch(path).unwrap();
+
+        assert_eq!(read_file(path).unwrap(), "keep me");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_touch_updates_modified_time() {
+        use std::time::Duration;
+
+        let path = "test_touch_mtime.txt";
+        write_file(path, "content").unwrap();
+
+        let an_hour_ago = SystemTime::now() - Duration::from_secs(3600);
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(an_hour_ago)
+            .unwrap();
+
+        touch(path).unwrap();
+
+        let modified = fs::metadata(path).unwrap().modified().unwrap();
+        assert!(modified > an_hour_ago + Duration::from_secs(60));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_numbered_alignment() {
+        let path = "test_read_numbered_alignment.txt";
+        let content: String = (1..=12).map(|i| format!("line {}\n", i)).collect();
+        write_file(path, &content).unwrap();
+
+        let result = read_file_numbered(path).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], " 1 | line 1");
+        assert_eq!(lines[8], " 9 | line 9");
+        assert_eq!(lines[11], "12 | line 12");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_numbered_no_trailing_newline() {
+        let path = "test_read_numbered_no_newline.txt";
+        write_file(path, "a\nb").unwrap();
+
+        assert_eq!(read_file_numbered(path).unwrap(), "1 | a\n2 | b\n");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_numbered_empty_file() {
+        let path = "test_read_numbered_empty.txt";
+        write_file(path, "").unwrap();
+
+        assert_eq!(read_file_numbered(path).unwrap(), "");
+
+        cleanup_file(path);
+    }
+
+    #[cfg(feature = "filelock")]
+    #[test]
+    fn test_append_locked_concurrent_threads() {
+        use std::thread;
+
+        let path = "test_append_locked_threads.txt";
+        cleanup_file(path);
+
+        let line = "x".repeat(4096);
+        let handles: Vec<_> = (0..8)
+            .map(|id| {
+                let line = format!("{}:{}\n", id, line);
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        append_to_file_locked(path, &line).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let result = read_file(path).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines.len(), 80);
+        for id in 0..8 {
+            let expected = format!("{}:{}", id, line);
+            assert_eq!(lines.iter().filter(|&&l| l == expected).count(), 10);
+        }
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_chunks_reassembles_file() {
+        let path = "test_read_chunks.txt";
+        let content = "abcdefghijklmnopqrstuvwxyz";
+        write_file(path, content).unwrap();
+
+        let mut chunks = Vec::new();
+        read_file_chunks(path, 8, |chunk| chunks.push(chunk.to_vec())).unwrap();
+
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![8, 8, 8, 2]);
+        assert_eq!(chunks.concat(), content.as_bytes());
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_chunks_larger_than_file() {
+        let path = "test_read_chunks_large.txt";
+        write_file(path, "short").unwrap();
+
+        let mut chunks = Vec::new();
+        read_file_chunks(path, 1024, |chunk| chunks.push(chunk.to_vec())).unwrap();
+        assert_eq!(chunks, vec![b"short".to_vec()]);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_chunks_exact_multiple_and_empty() {
+        let path = "test_read_chunks_exact.txt";
+        write_file(path, "abcdef").unwrap();
+
+        let mut count = 0;
+        read_file_chunks(path, 3, |_| count += 1).unwrap();
+        assert_eq!(count, 2);
+
+        write_file(path, "").unwrap();
+        let mut count = 0;
+        read_file_chunks(path, 3, |_| count += 1).unwrap();
+        assert_eq!(count, 0);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_chunks_errors() {
+        let path = "test_read_chunks_zero.txt";
+        write_file(path, "data").unwrap();
+
+        let err = read_file_chunks(path, 0, |_| {}).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        cleanup_file(path);
+
+        let err = read_file_chunks("test_read_chunks_missing.txt", 4, |_| {}).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_read_file_no_bom_strips_bom() {
+        let path = "test_read_no_bom_with.txt";
+        fs::write(path, b"\xEF\xBB\xBFid,name\n1,a\n").unwrap();
+
+        assert_eq!(read_file_no_bom(path).unwrap(), "id,name\n1,a\n");
+        // read_file keeps it
+        assert!(read_file(path).unwrap().starts_with('\u{FEFF}'));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_no_bom_without_bom() {
+        let path = "test_read_no_bom_without.txt";
+        // A BOM that isn't at the start is content, not a marker
+        let content = "id\u{FEFF},name";
+        write_file(path, content).unwrap();
+
+        assert_eq!(read_file_no_bom(path).unwrap(), content);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_file_no_bom_empty_file() {
+        let path = "test_read_no_bom_empty.txt";
+        write_file(path, "").unwrap();
+        assert_eq!(read_file_no_bom(path).unwrap(), "");
+
+        // A file holding only the BOM is empty after stripping
+        fs::write(path, b"\xEF\xBB\xBF").unwrap();
+        assert_eq!(read_file_no_bom(path).unwrap(), "");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_diff_lines_middle_difference() {
+        let (a, b) = ("test_diff_mid_a.txt", "test_diff_mid_b.txt");
+        write_file(a, "one\ntwo\nthree\n").unwrap();
+        write_file(b, "one\nTWO\nthree\n").unwrap();
+
+        assert_eq!(
+            diff_lines(a, b).unwrap(),
+            vec![(2, Some("two".to_string()), Some("TWO".to_string()))]
+        );
+
+        cleanup_file(a);
+        cleanup_file(b);
+    }
+
+    #[test]
+    fn test_diff_lines_different_lengths() {
+        let (a, b) = ("test_diff_len_a.txt", "test_diff_len_b.txt");
+        write_file(a, "one\ntwo\nthree\nfour").unwrap();
+        write_file(b, "one\ntwo").unwrap();
+
+        assert_eq!(
+            diff_lines(a, b).unwrap(),
+            vec![
+                (3, Some("three".to_string()), None),
+                (4, Some("four".to_string()), None),
+            ]
+        );
+        assert_eq!(
+            diff_lines(b, a).unwrap(),
+            vec![
+                (3, None, Some("three".to_string())),
+                (4, None, Some("four".to_string())),
+            ]
+        );
+
+        cleanup_file(a);
+        cleanup_file(b);
+    }
+
+    #[test]
+    fn test_diff_lines_identical() {
+        let (a, b) = ("test_diff_same_a.txt", "test_diff_same_b.txt");
+        write_file(a, "same\ncontent\n").unwrap();
+        write_file(b, "same\ncontent\n").unwrap();
+
+        assert!(diff_lines(a, b).unwrap().is_empty());
+        assert!(diff_lines(a, a).unwrap().is_empty());
+
+        cleanup_file(a);
+        cleanup_file(b);
+    }
+
+    #[test]
+    fn test_diff_lines_missing_file() {
+        let a = "test_diff_missing_a.txt";
+        write_file(a, "x").unwrap();
+
+        assert!(diff_lines(a, "test_diff_missing_b.txt").is_err());
+
+        cleanup_file(a);
+    }
+
+    #[test]
+    fn test_write_file_if_changed_new_file() {
+        let path = "test_write_if_changed_new.txt";
+        cleanup_file(path);
+
+        assert!(write_file_if_changed(path, "fresh").unwrap());
+        assert_eq!(read_file(path).unwrap(), "fresh");
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_write_file_if_changed_identical() {
+        let path = "test_write_if_changed_same.txt";
+        write_file(path, "same").unwrap();
+        let old_time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(old_time)
+            .unwrap();
+
+        assert!(!write_file_if_changed(path, "same").unwrap());
+        // The file was not touched
+        assert_eq!(fs::metadata(path).unwrap().modified().unwrap(), old_time);
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_write_file_if_changed_different() {
+        let path = "test_write_if_changed_diff.txt";
+        write_file(path, "old").unwrap();
+
+        assert!(write_file_if_changed(path, "new").unwrap());
+        assert_eq!(read_file(path).unwrap(), "new");
+        // A second identical write is skipped
+        assert!(!write_file_if_changed(path, "new").unwrap());
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_last_line_multi_line() {
+        let path = "test_read_last_line_multi.txt";
+
+        write_file(path, "first\nsecond\nthird\n").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some("third".to_string()));
+
+        write_file(path, "first\nsecond\nthird").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some("third".to_string()));
+
+        write_file(path, "first\r\nsecond\r\n").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some("second".to_string()));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_last_line_single_line() {
+        let path = "test_read_last_line_single.txt";
+
+        write_file(path, "only line").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some("only line".to_string()));
+
+        write_file(path, "only line\n").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some("only line".to_string()));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_last_line_empty_file() {
+        let path = "test_read_last_line_empty.txt";
+        write_file(path, "").unwrap();
+
+        assert_eq!(read_last_line(path).unwrap(), None);
+
+        // A lone newline is one empty line
+        write_file(path, "\n").unwrap();
+        assert_eq!(read_last_line(path).unwrap(), Some(String::new()));
+
+        cleanup_file(path);
+    }
+
+    #[test]
+    fn test_read_last_line_spans_blocks() {
+        let path = "test_read_last_line_blocks.txt";
+        let long_line = "é".repeat(5000);
+        write_file(path, &format!("{}\n{}\n", "x".repeat(9000), long_line)).unwrap();
+
+        assert_eq!(read_last_line(path).unwrap(), Some(long_line));
+
+        cleanup_file(path);
+    }
 }
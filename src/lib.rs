@@ -1,18 +1,58 @@
 mod date_utils;
+#[cfg(feature = "base64")]
+mod encoding;
 mod file_io_utils;
 mod math_utils;
+mod stats_utils;
 mod string_utils;
 
-// Re-export all public functions from math_utils
-pub use math_utils::{factorial, gcd, is_prime};
+// Re-export all public functions and types from math_utils
+#[cfg(feature = "bignum")]
+pub use math_utils::factorial_bignum;
+pub use math_utils::{
+    DivisorClass, GcdAccumulator, PrimeIter, UnsignedInt, ackermann, are_coprime, classify_number,
+    compare_fractions, continued_fraction, count_set_bits, crt, digit_sum_base, factorial,
+    factorial_ratio, fibonacci_up_to, gamma, gcd, gcd_iter, goldbach_pair, is_even,
+    is_numeric_palindrome, is_odd, is_pandigital, is_prime, is_prime_generic, is_triangular,
+    mod_add, mod_inverse, mod_mul, prime_pi, primorial, solve_quadratic, sqrt_newton, sum_of_cubes,
+    sum_of_squares, sum_primes_below, triangular,
+};
+
+// Re-export all public functions from stats_utils
+pub use stats_utils::{
+    mean_absolute_deviation, min_max_scale, mode_f64, percentile, weighted_mean, z_scores,
+};
 
-// Re-export all public functions from string_utils
-pub use string_utils::{count_char, is_palindrome, reverse_string};
+// Re-export all public functions and types from string_utils
+pub use string_utils::{
+    EditOp, acronym, center, count_byte, count_char, count_char_casefold, count_chars,
+    count_words_matching, damerau_levenshtein, display_width, edit_script, expand_tabs, from_hex,
+    graphemes, is_balanced, is_palindrome, is_palindrome_with, join_with_capacity, levenshtein,
+    levenshtein_ratio, levenshtein_within, line_count, longest_palindromic_substring,
+    most_common_chars, normalize_newlines, nth_grapheme, reverse_preserving_marks, reverse_string,
+    reverse_string_in_place, split_keep_delimiter, strip_ansi_codes, to_hex, to_pig_latin,
+    tokenize_with_positions, top_words, trim_report, word_frequency, wrap_text_hyphenate,
+};
 
 // Re-export all public functions and types from date_utils
 pub use date_utils::{
-    DateDifference, date_difference, format_date, parse_date, validate_date_format,
+    DateDifference, business_days_between_excluding, date_difference, date_range_step,
+    days_until_next_occurrence, easter_sunday, format_date, from_unix_timestamp,
+    full_years_between, humanize_days, inclusive_days_between, month_starts, parse_any, parse_date,
+    parse_date_ordered, to_unix_timestamp, validate_and_parse, validate_date_detailed,
+    validate_date_format, week_of_month,
+};
+
+// Re-export all public functions and types from file_io_utils
+#[cfg(feature = "filelock")]
+pub use file_io_utils::append_to_file_locked;
+pub use file_io_utils::{
+    LineDiff, append_to_file, append_to_file_counted, diff_lines, grep_file, read_file,
+    read_file_chunks, read_file_no_bom, read_file_numbered, read_file_optional,
+    read_file_or_default, read_last_line, touch, write_file, write_file_if_changed,
+    write_file_mkdir, write_file_with_mode,
 };
 
-// Re-export all public functions from file_io_utils
-pub use file_io_utils::{append_to_file, read_file, write_file};
+// Re-export all public functions from encoding
+#[cfg(feature = "base64")]
+pub use encoding::{from_base64, to_base64};
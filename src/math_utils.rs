@@ -1,3 +1,5 @@
+use once_cell::sync::OnceCell;
+
 /// Calculate the factorial of a number.
 ///
 /// Returns `None` if the result would overflow u64.
@@ -29,6 +31,106 @@ pub fn factorial(n: u64) -> Option<u64> {
     Some(result)
 }
 
+/// Calculate `n! / m!` without computing either factorial.
+///
+/// Multiplies `(m + 1)..=n` directly, so the ratio can be computed even when
+/// `n!` itself would overflow. Returns `Some(1)` when `n == m`, and `None` when
+/// `n < m` or the result would overflow u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::factorial_ratio;
+///
+/// // 25! overflows u64, but 25! / 23! = 25 * 24 fits
+/// assert_eq!(factorial_ratio(25, 23), Some(600));
+///
+/// assert_eq!(factorial_ratio(5, 5), Some(1));
+/// assert_eq!(factorial_ratio(3, 5), None);
+/// ```
+pub fn factorial_ratio(n: u64, m: u64) -> Option<u64> {
+    if n < m {
+        return None;
+    }
+
+    // Iterate over m..n and shift by one so that m == u64::MAX can't overflow
+    let mut result = 1u64;
+    for i in m..n {
+        result = result.checked_mul(i + 1)?;
+    }
+    Some(result)
+}
+
+/// Calculate the factorial of a number with arbitrary precision.
+///
+/// Unlike [`factorial`], this never overflows. Requires the `bignum` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::factorial_bignum;
+///
+/// assert_eq!(factorial_bignum(5).to_string(), "120");
+///
+/// // Far beyond what fits in a u64
+/// assert_eq!(factorial_bignum(100).to_string().len(), 158);
+/// ```
+#[cfg(feature = "bignum")]
+pub fn factorial_bignum(n: u64) -> num_bigint::BigUint {
+    (2..=n).fold(num_bigint::BigUint::from(1u32), |acc, i| acc * i)
+}
+
+/// Calculate the Gamma function, extending the factorial to real numbers.
+///
+/// For positive integers `gamma(n + 1) == n!`. Uses the Lanczos approximation
+/// (g = 7, 9 coefficients), accurate to roughly 15 significant digits, with the
+/// reflection formula for `x < 0.5`. Returns NaN at the poles (0, -1, -2, ...).
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::gamma;
+///
+/// assert!((gamma(5.0) - 24.0).abs() < 1e-9); // 4!
+/// assert!((gamma(0.5) - std::f64::consts::PI.sqrt()).abs() < 1e-9);
+/// assert!(gamma(-2.0).is_nan());
+/// ```
+pub fn gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    // sin(πx) is only approximately zero at the poles, so the reflection
+    // formula would give large finite values there
+    if x <= 0.0 && x.fract() == 0.0 {
+        return f64::NAN;
+    }
+
+    if x < 0.5 {
+        // Reflection formula: Γ(x)Γ(1 - x) = π / sin(πx)
+        let pi = std::f64::consts::PI;
+        return pi / ((pi * x).sin() * gamma(1.0 - x));
+    }
+
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (i, &c) in COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += c / (x + i as f64);
+    }
+
+    let t = x + G + 0.5;
+    (2.0 * std::f64::consts::PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum
+}
+
 /// Calculate the greatest common divisor (GCD) of two numbers using Euclidean algorithm.
 ///
 /// # Examples
@@ -54,11 +156,95 @@ pub fn gcd(mut a: u64, mut b: u64) -> u64 {
     a
 }
 
+/// Check if two numbers are coprime, i.e. their only common divisor is 1.
+///
+/// Note that 1 is coprime with every number, and 0 is coprime only with 1.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::are_coprime;
+///
+/// assert!(are_coprime(8, 15));
+/// assert!(!are_coprime(12, 18));
+/// assert!(are_coprime(1, 100));
+/// assert!(are_coprime(0, 1));
+/// assert!(!are_coprime(0, 5));
+/// ```
+pub fn are_coprime(a: u64, b: u64) -> bool {
+    gcd(a, b) == 1
+}
+
+/// Calculate the greatest common divisor of all numbers produced by an iterator.
+///
+/// Folds the values with [`gcd`]. Returns 0 for an empty iterator, since 0 is
+/// the identity element of `gcd`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::gcd_iter;
+///
+/// assert_eq!(gcd_iter(vec![12, 18, 24]), 6);
+///
+/// let values = [48, 18, 30];
+/// assert_eq!(gcd_iter(values.iter().copied()), 6);
+///
+/// assert_eq!(gcd_iter(std::iter::empty()), 0);
+/// ```
+pub fn gcd_iter<I: IntoIterator<Item = u64>>(iter: I) -> u64 {
+    iter.into_iter().fold(0, gcd)
+}
+
+/// Maintains the GCD of a stream of numbers as they arrive.
+///
+/// Starts at 0, the identity for [`gcd`], so the value after pushing a single
+/// number is that number. Each push folds the new number in, matching
+/// [`gcd_iter`] over everything pushed so far.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::GcdAccumulator;
+///
+/// let mut acc = GcdAccumulator::new();
+/// assert_eq!(acc.value(), 0);
+///
+/// for x in [12, 18, 24] {
+///     acc.push(x);
+/// }
+/// assert_eq!(acc.value(), 6);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GcdAccumulator {
+    current: u64,
+}
+
+impl GcdAccumulator {
+    /// Create an empty accumulator with a value of 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold another number into the running GCD.
+    pub fn push(&mut self, x: u64) {
+        self.current = gcd(self.current, x);
+    }
+
+    /// The GCD of every number pushed so far, or 0 if none have been.
+    pub fn value(&self) -> u64 {
+        self.current
+    }
+}
+
 /// Check if a number is prime.
 ///
 /// Returns `true` if the number is prime, `false` otherwise.
 /// Note: 0 and 1 are not considered prime.
 ///
+/// Numbers below 1000 are looked up in a table of small primes that is built
+/// lazily on first use; the same table seeds trial division for larger numbers.
+///
 /// # Examples
 ///
 /// ```
@@ -74,116 +260,1898 @@ pub fn gcd(mut a: u64, mut b: u64) -> u64 {
 /// assert!(!is_prime(1));
 /// ```
 pub fn is_prime(n: u64) -> bool {
-    match n {
-        0 | 1 => false,
-        2 => true,
-        n if n % 2 == 0 => false,
-        _ => {
-            let sqrt_n = (n as f64).sqrt() as u64;
-            !(3..=sqrt_n).step_by(2).any(|i| n.is_multiple_of(i))
+    // Offsets within each block of 30 that are coprime to 2, 3, and 5
+    const WHEEL: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
+    if n < SMALL_PRIME_LIMIT {
+        return small_primes().binary_search(&n).is_ok();
+    }
+
+    let sqrt_n = n.isqrt();
+    for &p in small_primes() {
+        if p > sqrt_n {
+            return true;
+        }
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+
+    // Continue past the table with the wheel, skipping divisors already tried
+    let mut base = SMALL_PRIME_LIMIT / 30 * 30;
+    loop {
+        for offset in WHEEL {
+            let divisor = base + offset;
+            if divisor > sqrt_n {
+                return true;
+            }
+            if divisor >= SMALL_PRIME_LIMIT && n.is_multiple_of(divisor) {
+                return false;
+            }
         }
+        base += 30;
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Exclusive upper bound of the precomputed small-prime table.
+const SMALL_PRIME_LIMIT: u64 = 1000;
 
-    #[test]
-    fn test_factorial_small_numbers() {
-        assert_eq!(factorial(0), Some(1));
-        assert_eq!(factorial(1), Some(1));
-        assert_eq!(factorial(2), Some(2));
-        assert_eq!(factorial(3), Some(6));
-        assert_eq!(factorial(4), Some(24));
-        assert_eq!(factorial(5), Some(120));
-    }
+/// Primes below `SMALL_PRIME_LIMIT`, built on first use.
+static SMALL_PRIMES: OnceCell<Vec<u64>> = OnceCell::new();
 
-    #[test]
-    fn test_factorial_larger_numbers() {
-        assert_eq!(factorial(10), Some(3_628_800));
-        assert_eq!(factorial(15), Some(1_307_674_368_000));
-        assert_eq!(factorial(20), Some(2_432_902_008_176_640_000));
-    }
+/// Get the table of primes below `SMALL_PRIME_LIMIT`, building it if needed.
+fn small_primes() -> &'static [u64] {
+    SMALL_PRIMES.get_or_init(|| {
+        sieve(SMALL_PRIME_LIMIT - 1)
+            .iter()
+            .enumerate()
+            .filter(|&(_, &prime)| prime)
+            .map(|(n, _)| n as u64)
+            .collect()
+    })
+}
 
-    #[test]
-    fn test_factorial_overflow() {
-        assert_eq!(factorial(21), None);
-        assert_eq!(factorial(25), None);
-        assert_eq!(factorial(100), None);
+/// Calculate the sum of all primes strictly less than `limit`.
+///
+/// Uses a sieve of Eratosthenes, so memory grows linearly with `limit`.
+/// Returns 0 for `limit <= 2`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::sum_primes_below;
+///
+/// assert_eq!(sum_primes_below(10), 17); // 2 + 3 + 5 + 7
+/// assert_eq!(sum_primes_below(2), 0);
+/// ```
+pub fn sum_primes_below(limit: u64) -> u64 {
+    if limit <= 2 {
+        return 0;
     }
 
-    #[test]
-    fn test_gcd_basic() {
-        assert_eq!(gcd(48, 18), 6);
-        assert_eq!(gcd(18, 48), 6); // Commutative
-        assert_eq!(gcd(100, 50), 50);
-        assert_eq!(gcd(54, 24), 6);
-    }
+    sieve(limit - 1)
+        .iter()
+        .enumerate()
+        .filter(|&(_, &prime)| prime)
+        .map(|(n, _)| n as u64)
+        .sum()
+}
 
-    #[test]
-    fn test_gcd_coprime() {
-        assert_eq!(gcd(17, 19), 1);
-        assert_eq!(gcd(13, 7), 1);
+/// Count the primes less than or equal to `n` (the prime-counting function π(n)).
+///
+/// Uses a sieve of Eratosthenes, so memory grows linearly with `n`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::prime_pi;
+///
+/// assert_eq!(prime_pi(10), 4); // 2, 3, 5, 7
+/// assert_eq!(prime_pi(100), 25);
+/// assert_eq!(prime_pi(1), 0);
+/// ```
+pub fn prime_pi(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
     }
 
-    #[test]
-    fn test_gcd_same_numbers() {
-        assert_eq!(gcd(42, 42), 42);
-        assert_eq!(gcd(1, 1), 1);
+    sieve(n).iter().filter(|&&prime| prime).count() as u64
+}
+
+/// Build a sieve of Eratosthenes where `sieve[n]` is `true` if `n` is prime, for `n <= max`.
+fn sieve(max: u64) -> Vec<bool> {
+    let max = max as usize;
+    let mut is_prime = vec![true; max + 1];
+    is_prime[0] = false;
+    if max >= 1 {
+        is_prime[1] = false;
     }
 
-    #[test]
-    fn test_gcd_with_zero() {
-        assert_eq!(gcd(0, 5), 5);
-        assert_eq!(gcd(5, 0), 5);
-        assert_eq!(gcd(0, 0), 0);
+    let mut i = 2;
+    while i * i <= max {
+        if is_prime[i] {
+            for multiple in (i * i..=max).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+        i += 1;
     }
+    is_prime
+}
 
-    #[test]
-    fn test_gcd_large_numbers() {
-        assert_eq!(gcd(1_000_000, 500_000), 500_000);
-        assert_eq!(gcd(123_456_789, 987_654_321), 9);
+/// Express an even number as the sum of two primes (a Goldbach pair).
+///
+/// Returns the pair with the smallest possible first prime, ordered so that
+/// `p <= q`. Returns `None` for odd numbers and numbers below 4.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::goldbach_pair;
+///
+/// assert_eq!(goldbach_pair(28), Some((5, 23)));
+/// assert_eq!(goldbach_pair(4), Some((2, 2)));
+/// assert_eq!(goldbach_pair(3), None);
+/// assert_eq!(goldbach_pair(29), None);
+/// ```
+pub fn goldbach_pair(n: u64) -> Option<(u64, u64)> {
+    if n < 4 || !n.is_multiple_of(2) {
+        return None;
     }
 
-    #[test]
-    fn test_is_prime_small_primes() {
-        assert_eq!(is_prime(2), true);
-        assert_eq!(is_prime(3), true);
-        assert_eq!(is_prime(5), true);
-        assert_eq!(is_prime(7), true);
-        assert_eq!(is_prime(11), true);
-        assert_eq!(is_prime(13), true);
+    (2..=n / 2)
+        .find(|&p| is_prime(p) && is_prime(n - p))
+        .map(|p| (p, n - p))
+}
+
+mod sealed {
+    /// Supertrait that keeps [`super::UnsignedInt`] from being implemented
+    /// outside this crate.
+    pub trait Sealed {}
+}
+
+/// Unsigned integer types that can be losslessly widened to `u64`.
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64`, and `usize`, so the generic math
+/// functions accept any of these without casting at the call site. The trait
+/// is sealed: it can be named in bounds but not implemented for other types.
+///
+/// ```compile_fail
+/// use rust_utils_lib::UnsignedInt;
+///
+/// #[derive(Clone, Copy)]
+/// struct Wrapper(u8);
+///
+/// impl UnsignedInt for Wrapper {
+///     fn to_u64(self) -> u64 {
+///         self.0 as u64
+///     }
+/// }
+/// ```
+pub trait UnsignedInt: sealed::Sealed + Copy {
+    /// Widen the value to a `u64`.
+    fn to_u64(self) -> u64;
+}
+
+macro_rules! impl_unsigned_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl UnsignedInt for $t {
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_int!(u8, u16, u32, u64, usize);
+
+/// Check if a number of any unsigned integer type is prime.
+///
+/// Behaves exactly like [`is_prime`], which remains the default for `u64` inputs.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_prime_generic;
+///
+/// assert!(is_prime_generic(17u32));
+/// assert!(is_prime_generic(7usize));
+/// assert!(!is_prime_generic(255u8));
+/// ```
+pub fn is_prime_generic<T: UnsignedInt>(n: T) -> bool {
+    is_prime(n.to_u64())
+}
+
+/// Calculate the sum of the digits of a number written in the given base.
+///
+/// Returns `None` if `base` is outside the range 2–36.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::digit_sum_base;
+///
+/// // 0xff -> 15 + 15
+/// assert_eq!(digit_sum_base(255, 16), Some(30));
+///
+/// // 1234 -> 1 + 2 + 3 + 4
+/// assert_eq!(digit_sum_base(1234, 10), Some(10));
+///
+/// // Unsupported bases
+/// assert_eq!(digit_sum_base(10, 1), None);
+/// assert_eq!(digit_sum_base(10, 37), None);
+/// ```
+pub fn digit_sum_base(mut n: u64, base: u32) -> Option<u64> {
+    if !(2..=36).contains(&base) {
+        return None;
     }
 
-    #[test]
-    fn test_is_prime_small_composites() {
-        assert_eq!(is_prime(4), false);
-        assert_eq!(is_prime(6), false);
-        assert_eq!(is_prime(8), false);
-        assert_eq!(is_prime(9), false);
-        assert_eq!(is_prime(10), false);
+    let base = base as u64;
+    let mut sum = 0;
+    while n > 0 {
+        sum += n % base;
+        n /= base;
     }
+    Some(sum)
+}
 
-    #[test]
-    fn test_is_prime_edge_cases() {
-        assert_eq!(is_prime(0), false);
-        assert_eq!(is_prime(1), false);
+/// An infinite iterator over the prime numbers: 2, 3, 5, 7, 11, ...
+///
+/// Primes are generated lazily by trial division against the primes already
+/// yielded, so each new prime only costs divisions up to its square root.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::PrimeIter;
+///
+/// let first_five: Vec<u64> = PrimeIter::new().take(5).collect();
+/// assert_eq!(first_five, vec![2, 3, 5, 7, 11]);
+///
+/// let below_20: Vec<u64> = PrimeIter::new().take_while(|&p| p < 20).collect();
+/// assert_eq!(below_20, vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrimeIter {
+    primes: Vec<u64>,
+}
+
+impl PrimeIter {
+    /// Create a new iterator starting at 2.
+    pub fn new() -> Self {
+        Self::default()
     }
+}
 
-    #[test]
-    fn test_is_prime_larger_primes() {
-        assert_eq!(is_prime(17), true);
-        assert_eq!(is_prime(23), true);
-        assert_eq!(is_prime(97), true);
-        assert_eq!(is_prime(101), true);
-        assert_eq!(is_prime(1009), true);
+impl Iterator for PrimeIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let next = match self.primes.last() {
+            None => 2,
+            Some(2) => 3,
+            Some(&last) => {
+                let mut candidate = last + 2;
+                while self
+                    .primes
+                    .iter()
+                    .take_while(|&&p| p * p <= candidate)
+                    .any(|&p| candidate.is_multiple_of(p))
+                {
+                    candidate += 2;
+                }
+                candidate
+            }
+        };
+
+        self.primes.push(next);
+        Some(next)
     }
+}
 
-    #[test]
-    fn test_is_prime_larger_composites() {
-        assert_eq!(is_prime(100), false);
-        assert_eq!(is_prime(121), false); // 11 * 11
-        assert_eq!(is_prime(1000), false);
+/// Calculate the sum of the first `n` squares, `1² + 2² + ... + n²`.
+///
+/// Uses the closed form `n(n + 1)(2n + 1) / 6`. Returns `None` if the result
+/// would overflow u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::sum_of_squares;
+///
+/// assert_eq!(sum_of_squares(10), Some(385));
+/// assert_eq!(sum_of_squares(0), Some(0));
+/// assert_eq!(sum_of_squares(u64::MAX), None);
+/// ```
+pub fn sum_of_squares(n: u64) -> Option<u64> {
+    let n = n as u128;
+    let product = n.checked_mul(n + 1)?.checked_mul(2 * n + 1)?;
+    u64::try_from(product / 6).ok()
+}
+
+/// Calculate the sum of the first `n` cubes, `1³ + 2³ + ... + n³`.
+///
+/// Uses the identity that the sum of cubes is the square of `1 + 2 + ... + n`.
+/// Returns `None` if the result would overflow u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::sum_of_cubes;
+///
+/// assert_eq!(sum_of_cubes(3), Some(36));
+/// assert_eq!(sum_of_cubes(0), Some(0));
+/// assert_eq!(sum_of_cubes(u64::MAX), None);
+/// ```
+pub fn sum_of_cubes(n: u64) -> Option<u64> {
+    let n = n as u128;
+    let sum = n * (n + 1) / 2;
+    u64::try_from(sum.checked_mul(sum)?).ok()
+}
+
+/// Calculate the `n`th triangular number, `1 + 2 + ... + n = n(n + 1) / 2`.
+///
+/// Returns `None` if the result would overflow u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::triangular;
+///
+/// assert_eq!(triangular(10), Some(55));
+/// assert_eq!(triangular(0), Some(0));
+/// assert_eq!(triangular(u64::MAX), None);
+/// ```
+pub fn triangular(n: u64) -> Option<u64> {
+    let n = n as u128;
+    u64::try_from(n * (n + 1) / 2).ok()
+}
+
+/// Check whether `x` is a triangular number.
+///
+/// `x` is triangular exactly when `8x + 1` is a perfect square. The check is
+/// done with an integer square root in u128, so it is exact for every u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_triangular;
+///
+/// assert!(is_triangular(55));
+/// assert!(!is_triangular(56));
+/// assert!(is_triangular(0));
+/// ```
+pub fn is_triangular(x: u64) -> bool {
+    let discriminant = 8 * x as u128 + 1;
+    let root = discriminant.isqrt();
+    root * root == discriminant
+}
+
+/// Classification of a number by the sum of its proper divisors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisorClass {
+    /// The proper divisors sum to exactly the number (e.g. 6 = 1 + 2 + 3).
+    Perfect,
+    /// The proper divisors sum to more than the number (e.g. 12).
+    Abundant,
+    /// The proper divisors sum to less than the number (e.g. 8).
+    Deficient,
+}
+
+/// Classify a number as perfect, abundant, or deficient.
+///
+/// Compares `n` with the sum of its proper divisors (all divisors except `n`
+/// itself). Zero has no proper divisors and is reported as deficient.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::{DivisorClass, classify_number};
+///
+/// assert_eq!(classify_number(6), DivisorClass::Perfect);
+/// assert_eq!(classify_number(12), DivisorClass::Abundant);
+/// assert_eq!(classify_number(8), DivisorClass::Deficient);
+/// ```
+pub fn classify_number(n: u64) -> DivisorClass {
+    if n == 0 {
+        return DivisorClass::Deficient;
+    }
+
+    let sum = sum_of_proper_divisors(n);
+    match sum.cmp(&(n as u128)) {
+        std::cmp::Ordering::Equal => DivisorClass::Perfect,
+        std::cmp::Ordering::Greater => DivisorClass::Abundant,
+        std::cmp::Ordering::Less => DivisorClass::Deficient,
+    }
+}
+
+/// Sum the divisors of `n` other than `n` itself, pairing each divisor below
+/// the square root with its cofactor. Accumulates in u128 so the sum of a
+/// large abundant number cannot overflow.
+fn sum_of_proper_divisors(n: u64) -> u128 {
+    if n < 2 {
+        return 0;
+    }
+
+    let mut sum = 1u128;
+    let mut d = 2;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            sum += d as u128;
+            let pair = n / d;
+            if pair != d {
+                sum += pair as u128;
+            }
+        }
+        d += 1;
+    }
+    sum
+}
+
+/// Calculate the modular multiplicative inverse of `a` modulo `modulus`.
+///
+/// Returns the `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None`
+/// when no inverse exists because `a` and `modulus` share a factor, or when
+/// `modulus` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::mod_inverse;
+///
+/// // 3 * 4 = 12 ≡ 1 (mod 11)
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+///
+/// // 4 and 8 share a factor of 4
+/// assert_eq!(mod_inverse(4, 8), None);
+/// ```
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(modulus as i128) as u64)
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` with `g = gcd(a, b)` and
+/// `a * x + b * y = g`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// Solve a system of congruences `x ≡ residues[i] (mod moduli[i])` with the
+/// Chinese Remainder Theorem.
+///
+/// The moduli must be pairwise coprime. Returns the smallest non-negative
+/// solution together with the combined modulus (the product of all moduli).
+/// Negative residues are allowed. Returns `None` if the slices differ in
+/// length, a modulus is zero, two moduli share a factor, or the combined
+/// modulus or solution does not fit the return types. An empty system is
+/// solved by `(0, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::crt;
+///
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+/// assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+///
+/// // 4 and 6 are not coprime
+/// assert_eq!(crt(&[1, 3], &[4, 6]), None);
+/// ```
+pub fn crt(residues: &[i64], moduli: &[u64]) -> Option<(i64, u64)> {
+    if residues.len() != moduli.len() {
+        return None;
+    }
+
+    let mut solution = 0u128;
+    let mut combined = 1u64;
+    for (&residue, &modulus) in residues.iter().zip(moduli) {
+        if modulus == 0 {
+            return None;
+        }
+
+        let m = modulus as u128;
+        let target = (residue as i128).rem_euclid(modulus as i128) as u128;
+        let inverse = mod_inverse(combined % modulus, modulus)? as u128;
+
+        // Find k so that solution + combined * k ≡ target (mod modulus)
+        let diff = (target + m - solution % m) % m;
+        let k = diff * inverse % m;
+
+        solution += combined as u128 * k;
+        combined = combined.checked_mul(modulus)?;
+    }
+
+    Some((i64::try_from(solution).ok()?, combined))
+}
+
+/// Compare two fractions `(numerator, denominator)` exactly, without floating point.
+///
+/// Cross-multiplies in i128, so no input can overflow. Negative denominators
+/// are allowed and normalized, so `(1, -2)` is less than `(0, 1)`. Returns
+/// `None` if either denominator is zero.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::compare_fractions;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(compare_fractions((1, 3), (1, 2)), Some(Ordering::Less));
+/// assert_eq!(compare_fractions((2, 4), (1, 2)), Some(Ordering::Equal));
+/// assert_eq!(compare_fractions((1, 0), (1, 2)), None);
+/// ```
+pub fn compare_fractions(a: (i64, i64), b: (i64, i64)) -> Option<std::cmp::Ordering> {
+    if a.1 == 0 || b.1 == 0 {
+        return None;
+    }
+
+    // Move each sign onto the numerator so both denominators are positive
+    let normalize = |(num, den): (i64, i64)| {
+        let (num, den) = (num as i128, den as i128);
+        if den < 0 { (-num, -den) } else { (num, den) }
+    };
+    let (a_num, a_den) = normalize(a);
+    let (b_num, b_den) = normalize(b);
+
+    Some((a_num * b_den).cmp(&(b_num * a_den)))
+}
+
+/// Calculate `(a + b) mod m` without overflow.
+///
+/// The sum is computed in u128, so any u64 operands are safe.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::mod_add;
+///
+/// assert_eq!(mod_add(5, 9, 7), 0);
+/// assert_eq!(mod_add(u64::MAX, u64::MAX, 10), 0);
+/// ```
+pub fn mod_add(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+/// Calculate `(a * b) mod m` without overflow.
+///
+/// The product is computed in u128, so any u64 operands are safe.
+///
+/// # Panics
+///
+/// Panics if `m` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::mod_mul;
+///
+/// assert_eq!(mod_mul(6, 7, 10), 2);
+/// assert_eq!(mod_mul(u64::MAX, u64::MAX, 13), 4);
+/// ```
+pub fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Check whether the decimal digits of `n` are exactly `1..=k` for some `k`.
+///
+/// Each digit from 1 up to the number of digits must appear exactly once,
+/// in any order, and 0 may not appear. Returns `false` for 0.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_pandigital;
+///
+/// assert!(is_pandigital(123));
+/// assert!(is_pandigital(132));
+/// assert!(is_pandigital(918_273_645));
+/// assert!(!is_pandigital(122));
+/// assert!(!is_pandigital(1023));
+/// ```
+pub fn is_pandigital(mut n: u64) -> bool {
+    let mut seen = 0u16;
+    let mut digits = 0;
+    while n > 0 {
+        let bit = 1 << (n % 10);
+        if seen & bit != 0 {
+            return false;
+        }
+        seen |= bit;
+        digits += 1;
+        n /= 10;
+    }
+
+    // Bits 1..=digits set, bit 0 clear
+    digits > 0 && seen == ((1 << (digits + 1)) - 2)
+}
+
+/// Check whether the decimal digits of `n` read the same forwards and backwards.
+///
+/// Reverses the digits arithmetically instead of formatting a string. Single
+/// digits are palindromes; numbers ending in 0 (other than 0 itself) are not,
+/// since no number starts with 0.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_numeric_palindrome;
+///
+/// assert!(is_numeric_palindrome(12321));
+/// assert!(is_numeric_palindrome(7));
+/// assert!(!is_numeric_palindrome(120));
+/// ```
+pub fn is_numeric_palindrome(n: u64) -> bool {
+    // Reversed digits of a u64 can exceed u64::MAX, so accumulate in u128
+    let mut remaining = n;
+    let mut reversed = 0u128;
+    while remaining > 0 {
+        reversed = reversed * 10 + (remaining % 10) as u128;
+        remaining /= 10;
+    }
+    reversed == n as u128
+}
+
+/// Count the 1 bits in the binary representation of `n` (its population count).
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::count_set_bits;
+///
+/// assert_eq!(count_set_bits(0b1011), 3);
+/// assert_eq!(count_set_bits(0), 0);
+/// assert_eq!(count_set_bits(u64::MAX), 64);
+/// ```
+pub fn count_set_bits(n: u64) -> u32 {
+    n.count_ones()
+}
+
+/// Check whether `n` is even.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_even;
+///
+/// assert!(is_even(0));
+/// assert!(is_even(42));
+/// assert!(!is_even(7));
+/// ```
+pub fn is_even(n: u64) -> bool {
+    n & 1 == 0
+}
+
+/// Check whether `n` is odd.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_odd;
+///
+/// assert!(is_odd(7));
+/// assert!(!is_odd(42));
+/// ```
+pub fn is_odd(n: u64) -> bool {
+    !is_even(n)
+}
+
+/// Expand the fraction `num / den` as a continued fraction.
+///
+/// Returns the quotients `[a0, a1, a2, ...]` produced by the Euclidean
+/// algorithm, so that `num / den = a0 + 1 / (a1 + 1 / (a2 + ...))`. When
+/// `num < den` the first quotient is 0. Returns an empty vector if `den` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::continued_fraction;
+///
+/// assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+/// assert_eq!(continued_fraction(3, 8), vec![0, 2, 1, 2]);
+/// assert!(continued_fraction(1, 0).is_empty());
+/// ```
+pub fn continued_fraction(mut num: u64, mut den: u64) -> Vec<u64> {
+    let mut quotients = Vec::new();
+    while den != 0 {
+        quotients.push(num / den);
+        (num, den) = (den, num % den);
+    }
+    quotients
+}
+
+/// List every Fibonacci number less than or equal to `limit`: 0, 1, 1, 2, 3, 5, ...
+///
+/// Stops before any value would exceed `limit` or overflow u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::fibonacci_up_to;
+///
+/// assert_eq!(fibonacci_up_to(10), vec![0, 1, 1, 2, 3, 5, 8]);
+/// assert_eq!(fibonacci_up_to(0), vec![0]);
+/// ```
+pub fn fibonacci_up_to(limit: u64) -> Vec<u64> {
+    let mut sequence = vec![0];
+    let (mut current, mut next) = (0u64, 1u64);
+
+    while next <= limit {
+        sequence.push(next);
+        match current.checked_add(next) {
+            Some(sum) => (current, next) = (next, sum),
+            None => break,
+        }
+    }
+    sequence
+}
+
+/// Calculate the square root of `x` with Newton's method.
+///
+/// Iterates `guess = (guess + x / guess) / 2` until successive estimates
+/// differ by less than `epsilon`, or until rounding stops the estimate from
+/// improving. Returns `Some(0.0)` for 0.0, `Some(x)` for infinity, and `None`
+/// if `x` is negative or NaN, or `epsilon` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::sqrt_newton;
+///
+/// let root = sqrt_newton(2.0, 1e-12).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-12);
+///
+/// assert_eq!(sqrt_newton(0.0, 1e-9), Some(0.0));
+/// assert_eq!(sqrt_newton(-1.0, 1e-9), None);
+/// ```
+pub fn sqrt_newton(x: f64, epsilon: f64) -> Option<f64> {
+    if x.is_nan() || x < 0.0 || epsilon.is_nan() || epsilon <= 0.0 {
+        return None;
+    }
+    if x == 0.0 || x.is_infinite() {
+        return Some(x);
+    }
+
+    // Starting at or above the root, every estimate stays above it and shrinks
+    let mut guess = x.max(1.0);
+    loop {
+        let next = (guess + x / guess) / 2.0;
+        if (guess - next).abs() < epsilon || next >= guess {
+            return Some(next);
+        }
+        guess = next;
+    }
+}
+
+/// Find the real roots of `a·x² + b·x + c = 0`, sorted ascending.
+///
+/// Returns two roots, one (a double root, or the single root of a linear
+/// equation when `a == 0`), or none when there are no real roots. A
+/// degenerate equation with `a == 0` and `b == 0` has no single root to
+/// report and also returns an empty vector. The roots are computed with the
+/// numerically stable form `q = -(b + sign(b)·√(b² - 4ac)) / 2`, `x = q / a`,
+/// `x = c / q`, which avoids the cancellation the textbook formula suffers
+/// when `b²` is much larger than `4ac`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::solve_quadratic;
+///
+/// assert_eq!(solve_quadratic(1.0, -3.0, 2.0), vec![1.0, 2.0]);
+/// assert_eq!(solve_quadratic(1.0, 2.0, 1.0), vec![-1.0]);
+/// assert!(solve_quadratic(1.0, 0.0, 1.0).is_empty());
+/// assert_eq!(solve_quadratic(0.0, 2.0, -4.0), vec![2.0]);
+/// ```
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a == 0.0 {
+        return if b == 0.0 { Vec::new() } else { vec![-c / b] };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant.is_nan() || discriminant < 0.0 {
+        return Vec::new();
+    }
+    if discriminant == 0.0 {
+        return vec![-b / (2.0 * a)];
+    }
+
+    let q = -0.5 * (b + discriminant.sqrt().copysign(b));
+    let (x1, x2) = (q / a, c / q);
+    if x1 <= x2 { vec![x1, x2] } else { vec![x2, x1] }
+}
+
+/// Compute the Ackermann function A(m, n), returning `None` if the result
+/// overflows `u64`.
+///
+/// Evaluation is iterative, using an explicit stack of pending `m` values in
+/// place of recursion, so large inputs can't overflow the call stack. Levels
+/// `m <= 3` are evaluated with their closed forms (for example
+/// A(3, n) = 2^(n + 3) - 3), and branches known to overflow are cut off
+/// early: A(4, n) for n >= 2 and A(m, n) for m >= 5, n >= 1.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::ackermann;
+///
+/// assert_eq!(ackermann(2, 3), Some(9));
+/// assert_eq!(ackermann(3, 3), Some(61));
+/// assert_eq!(ackermann(4, 1), Some(65533));
+/// assert_eq!(ackermann(4, 2), None);
+/// ```
+pub fn ackermann(m: u64, n: u64) -> Option<u64> {
+    let mut stack = vec![m];
+    let mut n = n;
+
+    while let Some(m) = stack.pop() {
+        n = match m {
+            0 => n.checked_add(1)?,
+            1 => n.checked_add(2)?,
+            2 => n.checked_mul(2)?.checked_add(3)?,
+            // 2^(n + 3) - 3, written so that n = 61 (2^64 - 3) doesn't overflow
+            3 if n <= 61 => (u64::MAX >> (61 - n)) - 2,
+            3 => return None,
+            // A(m, 0) = A(m - 1, 1)
+            _ if n == 0 => {
+                stack.push(m - 1);
+                1
+            }
+            _ if m > 4 || n > 1 => return None,
+            // A(m, n) = A(m - 1, A(m, n - 1))
+            _ => {
+                stack.push(m - 1);
+                stack.push(m);
+                n - 1
+            }
+        };
+    }
+
+    Some(n)
+}
+
+/// Compute the primorial of `n`: the product of the first `n` primes.
+///
+/// The primes come from [`PrimeIter`], and the product stops at the first
+/// overflow, so large `n` returns `None` quickly. The primorial of 15 is the
+/// largest that fits in u64.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::primorial;
+///
+/// assert_eq!(primorial(0), Some(1));
+/// assert_eq!(primorial(3), Some(30)); // 2 * 3 * 5
+/// assert_eq!(primorial(16), None);
+/// ```
+pub fn primorial(n: u64) -> Option<u64> {
+    (0..n)
+        .zip(PrimeIter::new())
+        .try_fold(1u64, |product, (_, p)| product.checked_mul(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial_small_numbers() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(1), Some(1));
+        assert_eq!(factorial(2), Some(2));
+        assert_eq!(factorial(3), Some(6));
+        assert_eq!(factorial(4), Some(24));
+        assert_eq!(factorial(5), Some(120));
+    }
+
+    #[test]
+    fn test_factorial_larger_numbers() {
+        assert_eq!(factorial(10), Some(3_628_800));
+        assert_eq!(factorial(15), Some(1_307_674_368_000));
+        assert_eq!(factorial(20), Some(2_432_902_008_176_640_000));
+    }
+
+    #[test]
+    fn test_factorial_overflow() {
+        assert_eq!(factorial(21), None);
+        assert_eq!(factorial(25), None);
+        assert_eq!(factorial(100), None);
+    }
+
+    #[test]
+    fn test_factorial_ratio_basic() {
+        assert_eq!(factorial_ratio(5, 3), Some(20));
+        assert_eq!(factorial_ratio(10, 0), factorial(10));
+        assert_eq!(factorial_ratio(25, 23), Some(600));
+    }
+
+    #[test]
+    fn test_factorial_ratio_equal() {
+        assert_eq!(factorial_ratio(0, 0), Some(1));
+        assert_eq!(factorial_ratio(100, 100), Some(1));
+        assert_eq!(factorial_ratio(u64::MAX, u64::MAX), Some(1));
+        assert_eq!(factorial_ratio(u64::MAX, u64::MAX - 1), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_factorial_ratio_n_less_than_m() {
+        assert_eq!(factorial_ratio(3, 5), None);
+        assert_eq!(factorial_ratio(0, 1), None);
+    }
+
+    #[test]
+    fn test_factorial_ratio_overflow() {
+        // 20! fits in u64, 21! does not
+        assert_eq!(factorial_ratio(20, 0), Some(2_432_902_008_176_640_000));
+        assert_eq!(factorial_ratio(21, 0), None);
+        // Two large factors still fit, three do not
+        assert_eq!(
+            factorial_ratio(u64::MAX / 4, u64::MAX / 4 - 1),
+            Some(u64::MAX / 4)
+        );
+        assert_eq!(factorial_ratio(1 << 32, (1 << 32) - 3), None);
+    }
+
+    #[test]
+    fn test_gamma_integers_match_factorial() {
+        assert!((gamma(5.0) - 24.0).abs() < 1e-9);
+        for n in 0..=15u64 {
+            let expected = factorial(n).unwrap() as f64;
+            let relative_error = (gamma(n as f64 + 1.0) - expected).abs() / expected;
+            assert!(relative_error < 1e-12, "gamma({}) is off", n + 1);
+        }
+    }
+
+    #[test]
+    fn test_gamma_half_integers() {
+        let sqrt_pi = std::f64::consts::PI.sqrt();
+        assert!((gamma(0.5) - sqrt_pi).abs() < 1e-9);
+        assert!((gamma(1.5) - sqrt_pi / 2.0).abs() < 1e-9);
+        assert!((gamma(2.5) - 3.0 * sqrt_pi / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gamma_negative_non_integer() {
+        // Γ(-0.5) = -2√π
+        let expected = -2.0 * std::f64::consts::PI.sqrt();
+        assert!((gamma(-0.5) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gamma_poles() {
+        for x in [0.0, -0.0, -1.0, -2.0, -3.0, -10.0, -171.0] {
+            assert!(gamma(x).is_nan(), "gamma({}) should be NaN", x);
+        }
+        // Just beside a pole the value is large but finite
+        assert!(gamma(-1.0 + 1e-9).is_finite());
+    }
+
+    #[test]
+    fn test_gcd_basic() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6); // Commutative
+        assert_eq!(gcd(100, 50), 50);
+        assert_eq!(gcd(54, 24), 6);
+    }
+
+    #[test]
+    fn test_gcd_coprime() {
+        assert_eq!(gcd(17, 19), 1);
+        assert_eq!(gcd(13, 7), 1);
+    }
+
+    #[test]
+    fn test_gcd_same_numbers() {
+        assert_eq!(gcd(42, 42), 42);
+        assert_eq!(gcd(1, 1), 1);
+    }
+
+    #[test]
+    fn test_gcd_with_zero() {
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_gcd_large_numbers() {
+        assert_eq!(gcd(1_000_000, 500_000), 500_000);
+        assert_eq!(gcd(123_456_789, 987_654_321), 9);
+    }
+
+    #[test]
+    fn test_are_coprime_pairs() {
+        assert!(are_coprime(8, 15));
+        assert!(are_coprime(17, 19));
+        assert!(are_coprime(14, 25));
+    }
+
+    #[test]
+    fn test_are_coprime_shared_factor() {
+        assert!(!are_coprime(12, 18));
+        assert!(!are_coprime(21, 14));
+        assert!(!are_coprime(7, 7));
+    }
+
+    #[test]
+    fn test_are_coprime_with_one_and_zero() {
+        for n in [0, 1, 2, 100, u64::MAX] {
+            assert!(are_coprime(1, n));
+        }
+        assert!(are_coprime(0, 1));
+        assert!(!are_coprime(0, 5));
+        assert!(!are_coprime(0, 0));
+    }
+
+    #[test]
+    fn test_gcd_iter_range() {
+        // Multiples of 7
+        assert_eq!(gcd_iter((1..=10).map(|i| i * 7)), 7);
+        // Consecutive integers are coprime
+        assert_eq!(gcd_iter(10..20), 1);
+    }
+
+    #[test]
+    fn test_gcd_iter_empty() {
+        assert_eq!(gcd_iter(Vec::new()), 0);
+        assert_eq!(gcd_iter(std::iter::empty()), 0);
+    }
+
+    #[test]
+    fn test_gcd_iter_single_value() {
+        assert_eq!(gcd_iter([42]), 42);
+        assert_eq!(gcd_iter([0]), 0);
+    }
+
+    #[test]
+    fn test_is_prime_small_primes() {
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(is_prime(5));
+        assert!(is_prime(7));
+        assert!(is_prime(11));
+        assert!(is_prime(13));
+    }
+
+    #[test]
+    fn test_is_prime_small_composites() {
+        assert!(!is_prime(4));
+        assert!(!is_prime(6));
+        assert!(!is_prime(8));
+        assert!(!is_prime(9));
+        assert!(!is_prime(10));
+    }
+
+    #[test]
+    fn test_is_prime_edge_cases() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+    }
+
+    #[test]
+    fn test_is_prime_larger_primes() {
+        assert!(is_prime(17));
+        assert!(is_prime(23));
+        assert!(is_prime(97));
+        assert!(is_prime(101));
+        assert!(is_prime(1009));
+    }
+
+    #[test]
+    fn test_is_prime_larger_composites() {
+        assert!(!is_prime(100));
+        assert!(!is_prime(121)); // 11 * 11
+        assert!(!is_prime(1000));
+    }
+
+    #[test]
+    fn test_is_prime_matches_brute_force() {
+        fn brute_force(n: u64) -> bool {
+            n >= 2
+                && (2..n)
+                    .take_while(|d| d * d <= n)
+                    .all(|d| !n.is_multiple_of(d))
+        }
+
+        for n in 0..=100_000 {
+            assert_eq!(is_prime(n), brute_force(n), "mismatch for {}", n);
+        }
+    }
+
+    #[test]
+    fn test_is_prime_wheel_boundaries() {
+        // Squares of primes coprime to 30 must be caught by the wheel
+        assert!(!is_prime(49)); // 7 * 7
+        assert!(!is_prime(961)); // 31 * 31
+        assert!(!is_prime(7 * 31));
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(1_000_000_007 * 3));
+    }
+
+    #[test]
+    fn test_sum_primes_below_small() {
+        assert_eq!(sum_primes_below(10), 17);
+        assert_eq!(sum_primes_below(11), 17); // Strictly below the limit
+        assert_eq!(sum_primes_below(12), 28);
+        assert_eq!(sum_primes_below(3), 2);
+    }
+
+    #[test]
+    fn test_sum_primes_below_edge_cases() {
+        assert_eq!(sum_primes_below(0), 0);
+        assert_eq!(sum_primes_below(1), 0);
+        assert_eq!(sum_primes_below(2), 0);
+    }
+
+    #[test]
+    fn test_sum_primes_below_two_million() {
+        assert_eq!(sum_primes_below(2_000_000), 142_913_828_922);
+    }
+
+    #[test]
+    fn test_sieve_matches_is_prime() {
+        let primes = sieve(1000);
+        for (n, &prime) in primes.iter().enumerate() {
+            assert_eq!(prime, is_prime(n as u64));
+        }
+        assert_eq!(sieve(0), vec![false]);
+        assert_eq!(sieve(1), vec![false, false]);
+    }
+
+    #[test]
+    fn test_goldbach_pair_valid() {
+        let (p, q) = goldbach_pair(28).unwrap();
+        assert!(is_prime(p) && is_prime(q));
+        assert_eq!(p + q, 28);
+        assert!(p <= q);
+        assert_eq!(goldbach_pair(10), Some((3, 7)));
+    }
+
+    #[test]
+    fn test_goldbach_pair_invalid() {
+        assert_eq!(goldbach_pair(0), None);
+        assert_eq!(goldbach_pair(2), None);
+        assert_eq!(goldbach_pair(3), None);
+        assert_eq!(goldbach_pair(9), None);
+    }
+
+    #[test]
+    fn test_goldbach_pair_holds_for_small_evens() {
+        for n in (4..=2000).step_by(2) {
+            let (p, q) = goldbach_pair(n).unwrap();
+            assert!(is_prime(p) && is_prime(q) && p + q == n && p <= q);
+        }
+    }
+
+    #[test]
+    fn test_is_prime_unchanged_with_small_prime_table() {
+        let from_sieve = sieve(2000);
+        for n in 0..=2000 {
+            assert_eq!(is_prime(n), from_sieve[n as usize], "mismatch for {}", n);
+        }
+    }
+
+    #[test]
+    fn test_small_prime_table_contents() {
+        // The table is built on first use; a lookup makes sure it exists
+        is_prime(7);
+        let table = SMALL_PRIMES.get().expect("table built by is_prime");
+        assert_eq!(table.len(), 168); // There are 168 primes below 1000
+        assert_eq!(table.first(), Some(&2));
+        assert_eq!(table.last(), Some(&997));
+    }
+
+    /// Environment variable marking the child process spawned by
+    /// `test_small_prime_table_is_lazy`.
+    const LAZY_CHILD_ENV: &str = "RUST_UTILS_SMALL_PRIMES_LAZY_CHILD";
+
+    #[test]
+    #[ignore = "run in a fresh process by test_small_prime_table_is_lazy"]
+    fn small_prime_table_lazy_child() {
+        // Other tests in the same process may already have built the table
+        if std::env::var_os(LAZY_CHILD_ENV).is_none() {
+            return;
+        }
+        assert!(SMALL_PRIMES.get().is_none());
+        is_prime(7);
+        assert!(SMALL_PRIMES.get().is_some());
+    }
+
+    #[test]
+    fn test_small_prime_table_is_lazy() {
+        // Rerun this test binary with only the child test, so nothing has
+        // touched the table before it checks
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "math_utils::tests::small_prime_table_lazy_child",
+                "--ignored",
+                "--test-threads=1",
+            ])
+            .env(LAZY_CHILD_ENV, "1")
+            .output()
+            .unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "child test failed:\n{}", stdout);
+        assert!(
+            stdout.contains("1 passed"),
+            "child test didn't run:\n{}",
+            stdout
+        );
+    }
+
+    #[test]
+    fn test_prime_iter_first_ten() {
+        let primes: Vec<u64> = PrimeIter::new().take(10).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_prime_iter_take_while() {
+        let primes: Vec<u64> = PrimeIter::new().take_while(|&p| p < 30).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_prime_iter_matches_is_prime() {
+        let from_iter: Vec<u64> = PrimeIter::new().take_while(|&p| p < 1000).collect();
+        let from_filter: Vec<u64> = (0..1000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(from_iter, from_filter);
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn test_factorial_bignum_matches_u64() {
+        for n in 0..=20 {
+            assert_eq!(
+                factorial_bignum(n),
+                num_bigint::BigUint::from(factorial(n).unwrap())
+            );
+        }
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn test_factorial_bignum_large() {
+        // 50! has 65 decimal digits
+        assert_eq!(factorial_bignum(50).to_string().len(), 65);
+        assert_eq!(
+            factorial_bignum(25).to_string(),
+            "15511210043330985984000000"
+        );
+    }
+
+    #[test]
+    fn test_digit_sum_base_decimal() {
+        assert_eq!(digit_sum_base(0, 10), Some(0));
+        assert_eq!(digit_sum_base(9, 10), Some(9));
+        assert_eq!(digit_sum_base(12345, 10), Some(15));
+    }
+
+    #[test]
+    fn test_digit_sum_base_binary() {
+        // In base 2 the digit sum is the number of set bits
+        assert_eq!(digit_sum_base(0b1011, 2), Some(3));
+        assert_eq!(digit_sum_base(255, 2), Some(8));
+        assert_eq!(digit_sum_base(1024, 2), Some(1));
+    }
+
+    #[test]
+    fn test_digit_sum_base_other_bases() {
+        assert_eq!(digit_sum_base(255, 16), Some(30));
+        assert_eq!(digit_sum_base(0o777, 8), Some(21));
+        assert_eq!(digit_sum_base(35, 36), Some(35)); // Single digit 'z'
+        assert_eq!(digit_sum_base(36, 36), Some(1)); // "10"
+    }
+
+    #[test]
+    fn test_digit_sum_base_invalid_base() {
+        assert_eq!(digit_sum_base(100, 0), None);
+        assert_eq!(digit_sum_base(100, 1), None);
+        assert_eq!(digit_sum_base(100, 37), None);
+    }
+
+    #[test]
+    fn test_is_prime_generic_u32() {
+        assert!(is_prime_generic(2u32));
+        assert!(is_prime_generic(4_294_967_291u32)); // Largest u32 prime
+        assert!(!is_prime_generic(u32::MAX));
+    }
+
+    #[test]
+    fn test_is_prime_generic_usize() {
+        assert!(is_prime_generic(97usize));
+        assert!(!is_prime_generic(0usize));
+        assert!(!is_prime_generic(100usize));
+    }
+
+    #[test]
+    fn test_is_prime_generic_matches_u64() {
+        for n in 0..=1000u64 {
+            assert_eq!(is_prime_generic(n as u8), is_prime(n as u8 as u64));
+            assert_eq!(is_prime_generic(n as u16), is_prime(n));
+            assert_eq!(is_prime_generic(n as u32), is_prime(n));
+            assert_eq!(is_prime_generic(n as usize), is_prime(n));
+        }
+    }
+
+    #[test]
+    fn test_sum_of_squares_closed_form() {
+        assert_eq!(sum_of_squares(10), Some(385));
+        for n in 0..100u64 {
+            let expected: u64 = (1..=n).map(|i| i * i).sum();
+            assert_eq!(sum_of_squares(n), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_sum_of_squares_overflow_boundary() {
+        assert_eq!(sum_of_squares(3_810_777), Some(18_446_735_571_075_162_805));
+        assert_eq!(sum_of_squares(3_810_778), None);
+        assert_eq!(sum_of_squares(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_sum_of_cubes_is_square_of_sum() {
+        for n in 0..100u64 {
+            let sum = n * (n + 1) / 2;
+            assert_eq!(sum_of_cubes(n), Some(sum * sum));
+            let expected: u64 = (1..=n).map(|i| i * i * i).sum();
+            assert_eq!(sum_of_cubes(n), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_sum_of_cubes_overflow_boundary() {
+        assert_eq!(sum_of_cubes(92_681), Some(18_446_425_603_259_108_841));
+        assert_eq!(sum_of_cubes(92_682), None);
+        assert_eq!(sum_of_cubes(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_triangular_basic() {
+        assert_eq!(triangular(0), Some(0));
+        assert_eq!(triangular(1), Some(1));
+        assert_eq!(triangular(10), Some(55));
+        assert_eq!(triangular(100), Some(5050));
+    }
+
+    #[test]
+    fn test_triangular_overflow_boundary() {
+        assert_eq!(triangular(6_074_000_999), Some(18_446_744_070_963_499_500));
+        assert_eq!(triangular(6_074_001_000), None);
+        assert_eq!(triangular(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_is_triangular_small() {
+        assert!(is_triangular(55));
+        assert!(!is_triangular(56));
+
+        let triangulars: Vec<u64> = (0..=200).filter(|&x| is_triangular(x)).collect();
+        let expected: Vec<u64> = (0..20)
+            .map_while(triangular)
+            .filter(|&t| t <= 200)
+            .collect();
+        assert_eq!(triangulars, expected);
+    }
+
+    #[test]
+    fn test_is_triangular_large() {
+        let largest = triangular(6_074_000_999).unwrap();
+        assert!(is_triangular(largest));
+        assert!(!is_triangular(largest - 1));
+        assert!(!is_triangular(largest + 1));
+        assert!(!is_triangular(u64::MAX));
+    }
+
+    #[test]
+    fn test_classify_number_examples() {
+        assert_eq!(classify_number(6), DivisorClass::Perfect);
+        assert_eq!(classify_number(12), DivisorClass::Abundant);
+        assert_eq!(classify_number(8), DivisorClass::Deficient);
+    }
+
+    #[test]
+    fn test_classify_number_perfect_numbers() {
+        let perfect: Vec<u64> = (1..10_000)
+            .filter(|&n| classify_number(n) == DivisorClass::Perfect)
+            .collect();
+        assert_eq!(perfect, vec![6, 28, 496, 8128]);
+    }
+
+    #[test]
+    fn test_classify_number_edge_cases() {
+        assert_eq!(classify_number(0), DivisorClass::Deficient);
+        assert_eq!(classify_number(1), DivisorClass::Deficient);
+        // Primes only have 1 as a proper divisor
+        assert_eq!(classify_number(97), DivisorClass::Deficient);
+        // Square: 1 + 2 + 4 + 8 = 15 < 16
+        assert_eq!(classify_number(16), DivisorClass::Deficient);
+    }
+
+    #[test]
+    fn test_sum_of_proper_divisors() {
+        assert_eq!(sum_of_proper_divisors(1), 0);
+        assert_eq!(sum_of_proper_divisors(12), 16);
+        assert_eq!(sum_of_proper_divisors(36), 55);
+    }
+
+    #[test]
+    fn test_mod_inverse_valid() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(1, 5), Some(1));
+    }
+
+    #[test]
+    fn test_mod_inverse_not_coprime() {
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 7), None);
+    }
+
+    #[test]
+    fn test_mod_inverse_zero_modulus() {
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_inverse_round_trip() {
+        let m = 1_000_000_007u64;
+        for a in [2u64, 3, 12345, 999_999_999, u64::MAX] {
+            let inv = mod_inverse(a, m).unwrap();
+            assert!(inv < m);
+            assert_eq!((a as u128 * inv as u128 % m as u128), 1);
+        }
+    }
+
+    #[test]
+    fn test_extended_gcd_bezout() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, g);
+    }
+
+    #[test]
+    fn test_crt_classic() {
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+    }
+
+    #[test]
+    fn test_crt_negative_residues() {
+        // x ≡ -1 (mod 4), x ≡ -1 (mod 9) → x ≡ 35 (mod 36)
+        assert_eq!(crt(&[-1, -1], &[4, 9]), Some((35, 36)));
+    }
+
+    #[test]
+    fn test_crt_invalid_input() {
+        assert_eq!(crt(&[1, 2], &[3]), None);
+        assert_eq!(crt(&[1, 3], &[4, 6]), None);
+        assert_eq!(crt(&[1], &[0]), None);
+    }
+
+    #[test]
+    fn test_crt_single_and_empty() {
+        assert_eq!(crt(&[17], &[5]), Some((2, 5)));
+        assert_eq!(crt(&[], &[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_combined_modulus_overflow() {
+        let moduli = [4_294_967_291u64, 4_294_967_279, 3];
+        assert_eq!(crt(&[1, 1, 1], &moduli), None);
+    }
+
+    #[test]
+    fn test_prime_pi_checkpoints() {
+        assert_eq!(prime_pi(0), 0);
+        assert_eq!(prime_pi(1), 0);
+        assert_eq!(prime_pi(2), 1);
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(100), 25);
+        assert_eq!(prime_pi(1000), 168);
+        assert_eq!(prime_pi(1_000_000), 78_498);
+    }
+
+    #[test]
+    fn test_prime_pi_counts_n_itself() {
+        // π steps up exactly at each prime
+        assert_eq!(prime_pi(96), 24);
+        assert_eq!(prime_pi(97), 25);
+    }
+
+    #[test]
+    fn test_compare_fractions_basic() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_fractions((1, 3), (1, 2)), Some(Ordering::Less));
+        assert_eq!(compare_fractions((3, 4), (2, 3)), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_compare_fractions_equal() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_fractions((2, 4), (1, 2)), Some(Ordering::Equal));
+        assert_eq!(compare_fractions((0, 5), (0, -3)), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_compare_fractions_negative_denominators() {
+        use std::cmp::Ordering;
+
+        // 1/-2 is -1/2
+        assert_eq!(compare_fractions((1, -2), (-1, 2)), Some(Ordering::Equal));
+        assert_eq!(compare_fractions((1, -2), (0, 1)), Some(Ordering::Less));
+        // -3/-4 is 3/4
+        assert_eq!(compare_fractions((-3, -4), (1, 2)), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_compare_fractions_zero_denominator() {
+        assert_eq!(compare_fractions((1, 0), (1, 2)), None);
+        assert_eq!(compare_fractions((1, 2), (0, 0)), None);
+    }
+
+    #[test]
+    fn test_compare_fractions_extreme_values() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            compare_fractions((i64::MAX, 1), (i64::MAX - 1, 1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_fractions((i64::MIN, -1), (i64::MAX, 1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_fractions((i64::MAX, i64::MAX - 1), (i64::MAX - 1, i64::MAX - 2)),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn test_mod_add_basic() {
+        assert_eq!(mod_add(5, 9, 7), 0);
+        assert_eq!(mod_add(0, 0, 1), 0);
+        assert_eq!(mod_add(3, 4, 100), 7);
+    }
+
+    #[test]
+    fn test_mod_add_large_operands() {
+        let m = 1_000_000_007u64;
+        for (a, b) in [(u64::MAX, u64::MAX), (u64::MAX, 1), (1 << 63, 1 << 63)] {
+            let expected = ((a as u128 + b as u128) % m as u128) as u64;
+            assert_eq!(mod_add(a, b, m), expected);
+        }
+        assert_eq!(mod_add(u64::MAX, 1, u64::MAX), 1);
+    }
+
+    #[test]
+    fn test_mod_mul_basic() {
+        assert_eq!(mod_mul(6, 7, 10), 2);
+        assert_eq!(mod_mul(0, 12345, 7), 0);
+    }
+
+    #[test]
+    fn test_mod_mul_large_operands() {
+        // 2^64 - 1 ≡ 2 (mod 13), so the product is 4
+        let expected = ((u64::MAX as u128 * u64::MAX as u128) % 13) as u64;
+        assert_eq!(mod_mul(u64::MAX, u64::MAX, 13), expected);
+
+        let m = u64::MAX - 58;
+        for (a, b) in [(u64::MAX, u64::MAX - 1), (1 << 40, 1 << 40), (m - 1, m - 1)] {
+            let expected = ((a as u128 * b as u128) % m as u128) as u64;
+            assert_eq!(mod_mul(a, b, m), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mod_mul_zero_modulus() {
+        mod_mul(3, 4, 0);
+    }
+
+    #[test]
+    fn test_is_pandigital_examples() {
+        assert!(is_pandigital(1));
+        assert!(is_pandigital(123));
+        assert!(is_pandigital(132));
+        assert!(is_pandigital(4231));
+    }
+
+    #[test]
+    fn test_is_pandigital_full_one_to_nine() {
+        assert!(is_pandigital(123_456_789));
+        assert!(is_pandigital(987_654_321));
+        assert!(is_pandigital(192_384_576));
+    }
+
+    #[test]
+    fn test_is_pandigital_non_examples() {
+        assert!(!is_pandigital(0));
+        assert!(!is_pandigital(2));
+        assert!(!is_pandigital(122));
+        assert!(!is_pandigital(124));
+        assert!(!is_pandigital(1023));
+        assert!(!is_pandigital(1_234_567_890));
+        assert!(!is_pandigital(u64::MAX));
+    }
+
+    #[test]
+    fn test_is_numeric_palindrome_single_digits() {
+        for n in 0..10 {
+            assert!(is_numeric_palindrome(n));
+        }
+    }
+
+    #[test]
+    fn test_is_numeric_palindrome_multi_digit() {
+        assert!(is_numeric_palindrome(12321));
+        assert!(is_numeric_palindrome(123_321));
+        assert!(is_numeric_palindrome(11));
+        assert!(!is_numeric_palindrome(123_456));
+        assert!(!is_numeric_palindrome(12));
+    }
+
+    #[test]
+    fn test_is_numeric_palindrome_trailing_zero() {
+        assert!(!is_numeric_palindrome(120));
+        assert!(!is_numeric_palindrome(10));
+        assert!(is_numeric_palindrome(1001));
+    }
+
+    #[test]
+    fn test_is_numeric_palindrome_large() {
+        assert!(!is_numeric_palindrome(u64::MAX));
+        assert!(is_numeric_palindrome(18_446_744_066_044_764_481));
+    }
+
+    #[test]
+    fn test_count_set_bits() {
+        assert_eq!(count_set_bits(0b1011), 3);
+        assert_eq!(count_set_bits(0), 0);
+        assert_eq!(count_set_bits(1 << 63), 1);
+        assert_eq!(count_set_bits(u64::MAX), 64);
+    }
+
+    #[test]
+    fn test_is_even_and_is_odd() {
+        for n in [0, 2, 100, u64::MAX - 1] {
+            assert!(is_even(n));
+            assert!(!is_odd(n));
+        }
+        for n in [1, 3, 99, u64::MAX] {
+            assert!(is_odd(n));
+            assert!(!is_even(n));
+        }
+    }
+
+    #[test]
+    fn test_continued_fraction_known_expansions() {
+        assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+        // 355/113, the classic approximation of π
+        assert_eq!(continued_fraction(355, 113), vec![3, 7, 16]);
+        assert_eq!(continued_fraction(10, 5), vec![2]);
+        assert_eq!(continued_fraction(7, 1), vec![7]);
+    }
+
+    #[test]
+    fn test_continued_fraction_num_less_than_den() {
+        assert_eq!(continued_fraction(3, 8), vec![0, 2, 1, 2]);
+        assert_eq!(continued_fraction(0, 5), vec![0]);
+    }
+
+    #[test]
+    fn test_continued_fraction_zero_denominator() {
+        assert!(continued_fraction(1, 0).is_empty());
+        assert!(continued_fraction(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_continued_fraction_fibonacci_ratio() {
+        // Ratios of consecutive Fibonacci numbers expand to all ones
+        let cf = continued_fraction(12_586_269_025, 7_778_742_049);
+        assert!(cf[..cf.len() - 1].iter().all(|&q| q == 1));
+    }
+
+    #[test]
+    fn test_fibonacci_up_to_small_limits() {
+        assert_eq!(fibonacci_up_to(0), vec![0]);
+        assert_eq!(fibonacci_up_to(1), vec![0, 1, 1]);
+        assert_eq!(fibonacci_up_to(2), vec![0, 1, 1, 2]);
+        assert_eq!(fibonacci_up_to(10), vec![0, 1, 1, 2, 3, 5, 8]);
+    }
+
+    #[test]
+    fn test_fibonacci_up_to_larger_limit() {
+        let fibs = fibonacci_up_to(1000);
+        assert_eq!(fibs.len(), 17);
+        assert_eq!(fibs.last(), Some(&987));
+        assert!(fibs.windows(3).all(|w| w[0] + w[1] == w[2]));
+    }
+
+    #[test]
+    fn test_fibonacci_up_to_max() {
+        // F(93) is the largest Fibonacci number that fits in u64
+        let fibs = fibonacci_up_to(u64::MAX);
+        assert_eq!(fibs.len(), 94);
+        assert_eq!(fibs.last(), Some(&12_200_160_415_121_876_738));
+    }
+
+    #[test]
+    fn test_sqrt_newton_matches_std() {
+        let epsilon = 1e-10;
+        for x in [1.0, 2.0, 9.0, 0.25, 1e-8, 12_345.678, 1e300] {
+            let root = sqrt_newton(x, epsilon).unwrap();
+            assert!(
+                (root - x.sqrt()).abs() < epsilon * x.sqrt().max(1.0),
+                "sqrt({}) = {}",
+                x,
+                root
+            );
+        }
+    }
+
+    #[test]
+    fn test_sqrt_newton_coarse_epsilon() {
+        let root = sqrt_newton(10.0, 0.1).unwrap();
+        assert!((root - 10f64.sqrt()).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_sqrt_newton_special_values() {
+        assert_eq!(sqrt_newton(0.0, 1e-9), Some(0.0));
+        assert_eq!(sqrt_newton(f64::INFINITY, 1e-9), Some(f64::INFINITY));
+        assert_eq!(sqrt_newton(-1.0, 1e-9), None);
+        assert_eq!(sqrt_newton(f64::NAN, 1e-9), None);
+        assert_eq!(sqrt_newton(4.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_solve_quadratic_two_roots() {
+        assert_eq!(solve_quadratic(1.0, -3.0, 2.0), vec![1.0, 2.0]);
+        assert_eq!(solve_quadratic(2.0, 0.0, -8.0), vec![-2.0, 2.0]);
+        assert_eq!(solve_quadratic(-1.0, 0.0, 9.0), vec![-3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_solve_quadratic_double_root() {
+        assert_eq!(solve_quadratic(1.0, 2.0, 1.0), vec![-1.0]);
+        assert_eq!(solve_quadratic(1.0, 0.0, 0.0), vec![0.0]);
+    }
+
+    #[test]
+    fn test_solve_quadratic_no_real_roots() {
+        assert!(solve_quadratic(1.0, 0.0, 1.0).is_empty());
+        assert!(solve_quadratic(1.0, 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_solve_quadratic_linear() {
+        assert_eq!(solve_quadratic(0.0, 2.0, -4.0), vec![2.0]);
+        assert!(solve_quadratic(0.0, 0.0, 5.0).is_empty());
+    }
+
+    #[test]
+    fn test_solve_quadratic_avoids_cancellation() {
+        // Roots are about -1e8 and -1e-8; the textbook formula loses the small one
+        let roots = solve_quadratic(1.0, 1e8, 1.0);
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0] + 1e8).abs() / 1e8 < 1e-15);
+        assert!((roots[1] + 1e-8).abs() / 1e-8 < 1e-15);
+    }
+
+    #[test]
+    fn test_gcd_accumulator_initial_value() {
+        assert_eq!(GcdAccumulator::new().value(), 0);
+        assert_eq!(GcdAccumulator::default().value(), 0);
+    }
+
+    #[test]
+    fn test_gcd_accumulator_push() {
+        let mut acc = GcdAccumulator::new();
+        acc.push(12);
+        assert_eq!(acc.value(), 12);
+        acc.push(18);
+        assert_eq!(acc.value(), 6);
+        acc.push(24);
+        assert_eq!(acc.value(), 6);
+        acc.push(7);
+        assert_eq!(acc.value(), 1);
+    }
+
+    #[test]
+    fn test_gcd_accumulator_zeros() {
+        let mut acc = GcdAccumulator::new();
+        acc.push(0);
+        assert_eq!(acc.value(), 0);
+        acc.push(10);
+        acc.push(0);
+        assert_eq!(acc.value(), 10);
+    }
+
+    #[test]
+    fn test_ackermann_known_values() {
+        assert_eq!(ackermann(0, 0), Some(1));
+        assert_eq!(ackermann(1, 2), Some(4));
+        assert_eq!(ackermann(2, 3), Some(9));
+        assert_eq!(ackermann(3, 3), Some(61));
+        assert_eq!(ackermann(4, 0), Some(13));
+        assert_eq!(ackermann(4, 1), Some(65533));
+        assert_eq!(ackermann(5, 0), Some(65533));
+    }
+
+    #[test]
+    fn test_ackermann_matches_recursive_definition() {
+        fn naive(m: u64, n: u64) -> u64 {
+            match (m, n) {
+                (0, n) => n + 1,
+                (m, 0) => naive(m - 1, 1),
+                (m, n) => naive(m - 1, naive(m, n - 1)),
+            }
+        }
+
+        for m in 0..=3 {
+            for n in 0..=5 {
+                assert_eq!(ackermann(m, n), Some(naive(m, n)), "A({}, {})", m, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ackermann_overflow() {
+        assert_eq!(ackermann(3, 60), Some((1 << 63) - 3));
+        assert_eq!(ackermann(3, 61), Some(u64::MAX - 2));
+        assert_eq!(ackermann(3, 62), None);
+        assert_eq!(ackermann(0, u64::MAX), None);
+        assert_eq!(ackermann(2, u64::MAX / 2), None);
+        assert_eq!(ackermann(4, 2), None);
+        assert_eq!(ackermann(5, 1), None);
+        assert_eq!(ackermann(6, 0), None);
+        assert_eq!(ackermann(u64::MAX, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_primorial_first_values() {
+        let expected = [1, 2, 6, 30, 210, 2310, 30030, 510510];
+        for (n, &value) in expected.iter().enumerate() {
+            assert_eq!(primorial(n as u64), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_primorial_overflow_boundary() {
+        assert_eq!(primorial(15), Some(614_889_782_588_491_410));
+        assert_eq!(primorial(16), None);
+        assert_eq!(primorial(u64::MAX), None);
     }
 }
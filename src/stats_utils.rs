@@ -0,0 +1,387 @@
+/// Find the most frequent value in a slice of floats, treating near-equal values as equal.
+///
+/// Values are sorted and grouped greedily into buckets: each bucket starts at its
+/// smallest value and contains every value within `tolerance` of it. The mean of
+/// the largest bucket is returned; ties go to the bucket with the smallest values.
+/// Returns `None` for empty input.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::mode_f64;
+///
+/// let readings = [1.0, 2.0, 2.01, 1.99, 3.0];
+/// let mode = mode_f64(&readings, 0.05).unwrap();
+/// assert!((mode - 2.0).abs() < 0.01);
+///
+/// assert_eq!(mode_f64(&[], 0.1), None);
+/// ```
+pub fn mode_f64(data: &[f64], tolerance: f64) -> Option<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let mut best: Option<&[f64]> = None;
+    let mut start = 0;
+    while start < sorted.len() {
+        let end = start
+            + sorted[start..]
+                .iter()
+                .take_while(|&&v| v - sorted[start] <= tolerance)
+                .count()
+                .max(1);
+        let bucket = &sorted[start..end];
+        if best.is_none_or(|b| bucket.len() > b.len()) {
+            best = Some(bucket);
+        }
+        start = end;
+    }
+
+    best.map(|bucket| bucket.iter().sum::<f64>() / bucket.len() as f64)
+}
+
+/// Calculate the `p`-th percentile of a slice using linear interpolation between ranks.
+///
+/// `p` ranges from `0.0` (the minimum) to `100.0` (the maximum); `50.0` gives the
+/// median. The input slice is not modified. Returns `None` for empty input or
+/// when `p` is outside `0.0..=100.0`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::percentile;
+///
+/// let latencies = [12.0, 15.0, 11.0, 30.0, 14.0];
+/// assert_eq!(percentile(&latencies, 50.0), Some(14.0));
+/// assert_eq!(percentile(&latencies, 0.0), Some(11.0));
+/// assert_eq!(percentile(&latencies, 100.0), Some(30.0));
+/// assert_eq!(percentile(&latencies, 25.0), Some(12.0));
+///
+/// assert_eq!(percentile(&[], 50.0), None);
+/// assert_eq!(percentile(&latencies, 101.0), None);
+/// ```
+pub fn percentile(data: &[f64], p: f64) -> Option<f64> {
+    if data.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f64;
+
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * fraction)
+}
+
+/// Calculate the weighted arithmetic mean of a slice of values.
+///
+/// Returns `None` if the slices have different lengths, are empty, or the
+/// weights sum to zero.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::weighted_mean;
+///
+/// assert_eq!(weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 0.0, 1.0]), Some(2.0));
+/// assert_eq!(weighted_mean(&[80.0, 90.0], &[3.0, 1.0]), Some(82.5));
+///
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), None);
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]), None);
+/// ```
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Option<f64> {
+    if values.is_empty() || values.len() != weights.len() {
+        return None;
+    }
+
+    let weight_sum: f64 = weights.iter().sum();
+    if weight_sum == 0.0 {
+        return None;
+    }
+
+    let weighted_sum: f64 = values.iter().zip(weights).map(|(v, w)| v * w).sum();
+    Some(weighted_sum / weight_sum)
+}
+
+/// Calculate the mean absolute deviation of a slice: the average distance from the mean.
+///
+/// Less sensitive to outliers than the standard deviation. Returns `None` for
+/// empty input.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::mean_absolute_deviation;
+///
+/// // Mean is 5, absolute deviations are 3, 1, 1, 3
+/// assert_eq!(mean_absolute_deviation(&[2.0, 4.0, 6.0, 8.0]), Some(2.0));
+/// assert_eq!(mean_absolute_deviation(&[]), None);
+/// ```
+pub fn mean_absolute_deviation(data: &[f64]) -> Option<f64> {
+    let mean = mean(data)?;
+    let total: f64 = data.iter().map(|v| (v - mean).abs()).sum();
+    Some(total / data.len() as f64)
+}
+
+/// Scale a slice linearly so its minimum maps to `0.0` and its maximum to `1.0`.
+///
+/// When every value is equal (zero range, including a single element), there is
+/// no meaningful scale and every value maps to `0.5`. Returns `None` for empty input.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::min_max_scale;
+///
+/// assert_eq!(min_max_scale(&[10.0, 15.0, 20.0]), Some(vec![0.0, 0.5, 1.0]));
+/// assert_eq!(min_max_scale(&[3.0, 3.0]), Some(vec![0.5, 0.5]));
+/// assert_eq!(min_max_scale(&[]), None);
+/// ```
+pub fn min_max_scale(data: &[f64]) -> Option<Vec<f64>> {
+    if data.is_empty() {
+        return None;
+    }
+
+    let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    if range == 0.0 {
+        return Some(vec![0.5; data.len()]);
+    }
+    Some(data.iter().map(|v| (v - min) / range).collect())
+}
+
+/// Standardize a slice into z-scores: `(value - mean) / population_std_dev`.
+///
+/// The result has mean 0 and standard deviation 1. Returns `None` for empty
+/// input or when the standard deviation is zero (all values equal).
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::z_scores;
+///
+/// let scores = z_scores(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+/// assert_eq!(scores[0], -1.5);
+/// assert_eq!(scores[7], 2.0);
+///
+/// assert_eq!(z_scores(&[1.0, 1.0]), None);
+/// ```
+pub fn z_scores(data: &[f64]) -> Option<Vec<f64>> {
+    let mean = mean(data)?;
+    let std_dev = population_std_dev(data)?;
+    // The computed mean of equal values like 0.1 can be off by rounding,
+    // leaving a tiny nonzero std dev, so check for equal values directly
+    if std_dev == 0.0 || data.iter().all(|&v| v == data[0]) {
+        return None;
+    }
+
+    Some(data.iter().map(|v| (v - mean) / std_dev).collect())
+}
+
+/// Calculate the arithmetic mean of a slice, or `None` if it is empty.
+fn mean(data: &[f64]) -> Option<f64> {
+    if data.is_empty() {
+        return None;
+    }
+    Some(data.iter().sum::<f64>() / data.len() as f64)
+}
+
+/// Calculate the population standard deviation of a slice, or `None` if it is empty.
+fn population_std_dev(data: &[f64]) -> Option<f64> {
+    let mean = mean(data)?;
+    let variance = data.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / data.len() as f64;
+    Some(variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests for mode_f64
+    #[test]
+    fn test_mode_f64_clusters_near_values() {
+        let data = [5.0, 5.001, 4.999, 1.0, 9.0];
+        let mode = mode_f64(&data, 0.01).unwrap();
+        assert!((mode - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mode_f64_all_distinct() {
+        // Every bucket has one value, so the smallest wins the tie
+        let data = [3.0, 1.0, 2.0];
+        assert_eq!(mode_f64(&data, 0.1), Some(1.0));
+    }
+
+    #[test]
+    fn test_mode_f64_exact_repeats() {
+        let data = [2.5, 7.0, 2.5, 7.0, 7.0];
+        assert_eq!(mode_f64(&data, 0.0), Some(7.0));
+    }
+
+    #[test]
+    fn test_mode_f64_empty() {
+        assert_eq!(mode_f64(&[], 1.0), None);
+    }
+
+    #[test]
+    fn test_mode_f64_single_value() {
+        assert_eq!(mode_f64(&[42.0], 0.5), Some(42.0));
+    }
+
+    // Tests for percentile
+    #[test]
+    fn test_percentile_median() {
+        // Odd length: the middle value
+        assert_eq!(percentile(&[3.0, 1.0, 2.0], 50.0), Some(2.0));
+        // Even length: the average of the two middle values
+        assert_eq!(percentile(&[4.0, 1.0, 3.0, 2.0], 50.0), Some(2.5));
+    }
+
+    #[test]
+    fn test_percentile_min_and_max() {
+        let data = [7.0, -2.0, 5.0, 10.0];
+        assert_eq!(percentile(&data, 0.0), Some(-2.0));
+        assert_eq!(percentile(&data, 100.0), Some(10.0));
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        let data = [10.0, 20.0, 30.0, 40.0, 50.0];
+        assert_eq!(percentile(&data, 25.0), Some(20.0));
+        assert_eq!(percentile(&data, 90.0), Some(46.0));
+    }
+
+    #[test]
+    fn test_percentile_invalid_input() {
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile(&[1.0], -0.1), None);
+        assert_eq!(percentile(&[1.0], 100.1), None);
+        assert_eq!(percentile(&[1.0], f64::NAN), None);
+    }
+
+    #[test]
+    fn test_percentile_does_not_mutate_input() {
+        let data = [3.0, 1.0, 2.0];
+        percentile(&data, 50.0);
+        assert_eq!(data, [3.0, 1.0, 2.0]);
+    }
+
+    // Tests for weighted_mean
+    #[test]
+    fn test_weighted_mean_basic() {
+        assert_eq!(weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 0.0, 1.0]), Some(2.0));
+        assert_eq!(weighted_mean(&[10.0, 20.0], &[1.0, 3.0]), Some(17.5));
+    }
+
+    #[test]
+    fn test_weighted_mean_equal_weights_is_mean() {
+        assert_eq!(weighted_mean(&[2.0, 4.0, 9.0], &[2.0, 2.0, 2.0]), Some(5.0));
+    }
+
+    #[test]
+    fn test_weighted_mean_mismatched_lengths() {
+        assert_eq!(weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 1.0]), None);
+        assert_eq!(weighted_mean(&[1.0], &[1.0, 1.0]), None);
+    }
+
+    #[test]
+    fn test_weighted_mean_zero_weight_sum() {
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]), None);
+    }
+
+    #[test]
+    fn test_weighted_mean_empty() {
+        assert_eq!(weighted_mean(&[], &[]), None);
+    }
+
+    // Tests for mean_absolute_deviation
+    #[test]
+    fn test_mad_hand_computed() {
+        // Mean is 4, absolute deviations are 3, 2, 1, 6 -> 12 / 4
+        let data = [1.0, 2.0, 3.0, 10.0];
+        assert_eq!(mean(&data), Some(4.0));
+        assert_eq!(mean_absolute_deviation(&data), Some(3.0));
+    }
+
+    #[test]
+    fn test_mad_differs_from_std_dev() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        // Population std dev of this classic dataset is exactly 2.0
+        assert_eq!(population_std_dev(&data), Some(2.0));
+
+        // MAD is smaller because large deviations are not squared
+        assert_eq!(mean_absolute_deviation(&data), Some(1.5));
+    }
+
+    #[test]
+    fn test_mad_constant_and_empty() {
+        assert_eq!(mean_absolute_deviation(&[3.0, 3.0, 3.0]), Some(0.0));
+        assert_eq!(mean_absolute_deviation(&[42.0]), Some(0.0));
+        assert_eq!(mean_absolute_deviation(&[]), None);
+    }
+
+    // Tests for min_max_scale
+    #[test]
+    fn test_min_max_scale_normal_range() {
+        assert_eq!(
+            min_max_scale(&[2.0, 6.0, 4.0, 10.0]),
+            Some(vec![0.0, 0.5, 0.25, 1.0])
+        );
+        assert_eq!(min_max_scale(&[-1.0, 1.0]), Some(vec![0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_min_max_scale_single_element() {
+        assert_eq!(min_max_scale(&[7.0]), Some(vec![0.5]));
+    }
+
+    #[test]
+    fn test_min_max_scale_all_equal() {
+        assert_eq!(min_max_scale(&[4.0, 4.0, 4.0]), Some(vec![0.5, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_min_max_scale_empty() {
+        assert_eq!(min_max_scale(&[]), None);
+    }
+
+    // Tests for z_scores
+    #[test]
+    fn test_z_scores_mean_zero_std_dev_one() {
+        let data = [10.0, 12.0, 23.0, 23.0, 16.0, 23.0, 21.0, 16.0];
+        let scores = z_scores(&data).unwrap();
+
+        assert!(mean(&scores).unwrap().abs() < 1e-12);
+        assert!((population_std_dev(&scores).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_z_scores_known_values() {
+        let scores = z_scores(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+        assert_eq!(scores, vec![-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_z_scores_zero_std_dev() {
+        assert_eq!(z_scores(&[5.0, 5.0, 5.0]), None);
+        assert_eq!(z_scores(&[1.0]), None);
+    }
+
+    #[test]
+    fn test_z_scores_equal_values_with_rounding() {
+        // 0.1 and 0.3 aren't exactly representable, so the computed mean
+        // differs slightly from the values themselves
+        assert_eq!(z_scores(&[0.1, 0.1, 0.1]), None);
+        assert_eq!(z_scores(&[0.3; 10]), None);
+    }
+
+    #[test]
+    fn test_z_scores_empty() {
+        assert_eq!(z_scores(&[]), None);
+    }
+}
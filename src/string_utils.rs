@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
 /// Check if a string is a palindrome.
 ///
 /// A palindrome is a string that reads the same forward and backward,
@@ -19,18 +23,45 @@
 /// assert!(is_palindrome(""));
 /// ```
 pub fn is_palindrome(s: &str) -> bool {
-    let cleaned: String = s
+    is_palindrome_with(s, |c| c.is_alphanumeric())
+}
+
+/// Check if a string is a palindrome, considering only the characters selected by `keep`.
+///
+/// Characters for which `keep` returns `false` are ignored. The remaining
+/// characters are compared case-insensitively. [`is_palindrome`] is equivalent
+/// to `is_palindrome_with(s, |c| c.is_alphanumeric())`.
+///
+/// Each character is compared by its full lowercase expansion, so characters
+/// whose lowercase form is several characters long (such as 'İ') must mirror
+/// each other as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_palindrome_with;
+///
+/// // Ignore everything except letters
+/// assert!(is_palindrome_with("Madam, I'm Adam", |c| c.is_alphabetic()));
+///
+/// // Keep punctuation too, so the comma breaks the symmetry
+/// assert!(!is_palindrome_with("ab,ba!", |c| !c.is_whitespace()));
+/// ```
+pub fn is_palindrome_with<F: Fn(char) -> bool>(s: &str, keep: F) -> bool {
+    let forward = s.chars().filter(|&c| keep(c)).flat_map(char::to_lowercase);
+    let backward = s
         .chars()
-        .filter(|c| c.is_alphanumeric())
-        .map(|c| c.to_lowercase().next().unwrap())
-        .collect();
+        .rev()
+        .filter(|&c| keep(c))
+        .flat_map(char::to_lowercase);
 
-    cleaned == cleaned.chars().rev().collect::<String>()
+    forward.eq(backward)
 }
 
 /// Count the occurrences of a specific character in a string.
 ///
-/// This function is case-sensitive by default.
+/// This function is case-sensitive. See [`count_char_casefold`] for a
+/// case-insensitive count.
 ///
 /// # Examples
 ///
@@ -49,6 +80,174 @@ pub fn count_char(s: &str, target: char) -> usize {
     s.chars().filter(|&c| c == target).count()
 }
 
+/// Count the occurrences of a character in a string, ignoring case.
+///
+/// Both the string and `target` are case folded with full Unicode mappings
+/// before comparing, so characters whose case mapping changes length are
+/// handled: 'ß' matches "SS", and 'İ' matches its two-character lowercase
+/// form "i̇". Counts non-overlapping occurrences of the folded `target` in the
+/// folded string that start and end on the folds of whole original
+/// characters, so the 'i' inside the fold of 'İ' doesn't count as an 'i'.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::count_char_casefold;
+///
+/// assert_eq!(count_char_casefold("Rust rocks", 'r'), 2);
+/// assert_eq!(count_char_casefold("STRASSE", 'ß'), 1);
+/// assert_eq!(count_char_casefold("İstanbul İzmir", 'İ'), 2);
+/// assert_eq!(count_char_casefold("İzmir", 'i'), 1);
+/// ```
+pub fn count_char_casefold(s: &str, target: char) -> usize {
+    let needle: String = fold_case(target).collect();
+
+    // Byte offsets in the folded string where each original character's fold
+    // starts, plus the end offset
+    let mut haystack = String::new();
+    let mut boundaries = vec![0];
+    for c in s.chars() {
+        haystack.extend(fold_case(c));
+        boundaries.push(haystack.len());
+    }
+
+    let mut count = 0;
+    let mut next = 0;
+    for &start in &boundaries {
+        if start < next {
+            continue;
+        }
+        let end = start + needle.len();
+        if haystack[start..].starts_with(needle.as_str()) && boundaries.binary_search(&end).is_ok()
+        {
+            count += 1;
+            next = end;
+        }
+    }
+    count
+}
+
+/// Count several characters in a single pass over a string.
+///
+/// Returns a map with an entry for every character in `targets`; targets that
+/// never occur map to 0. Matching is case-sensitive, like [`count_char`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::count_chars;
+///
+/// let counts = count_chars("hello world", &['l', 'o', 'z']);
+/// assert_eq!(counts[&'l'], 3);
+/// assert_eq!(counts[&'o'], 2);
+/// assert_eq!(counts[&'z'], 0);
+/// ```
+pub fn count_chars(s: &str, targets: &[char]) -> HashMap<char, usize> {
+    let mut counts: HashMap<char, usize> = targets.iter().map(|&c| (c, 0)).collect();
+
+    for c in s.chars() {
+        if let Some(count) = counts.get_mut(&c) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// Find the `n` most frequent characters in a string.
+///
+/// Returns up to `n` `(char, count)` pairs sorted by descending count. Ties are
+/// broken by ascending character order so the result is deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::most_common_chars;
+///
+/// assert_eq!(most_common_chars("aaabbc", 2), vec![('a', 3), ('b', 2)]);
+/// assert_eq!(most_common_chars("hello", 1), vec![('l', 2)]);
+/// assert!(most_common_chars("aaabbc", 0).is_empty());
+/// ```
+pub fn most_common_chars(s: &str, n: usize) -> Vec<(char, usize)> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+
+    let mut ranked: Vec<(char, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.truncate(n);
+    ranked
+}
+
+/// Count how often each word appears in a string.
+///
+/// Words are maximal runs of alphanumeric characters, so whitespace and
+/// punctuation both act as separators. Words are lowercased before counting.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::word_frequency;
+///
+/// let freq = word_frequency("The cat and the hat.");
+/// assert_eq!(freq["the"], 2);
+/// assert_eq!(freq["hat"], 1);
+/// assert_eq!(freq.len(), 4);
+/// ```
+pub fn word_frequency(s: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in s
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Find the `n` most frequent words in a string.
+///
+/// Uses the same tokenization as [`word_frequency`]. Returns up to `n`
+/// `(word, count)` pairs sorted by descending count, with ties broken
+/// alphabetically so the result is deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::top_words;
+///
+/// let text = "to be, or not to be";
+/// assert_eq!(
+///     top_words(text, 3),
+///     vec![("be".to_string(), 2), ("to".to_string(), 2), ("not".to_string(), 1)]
+/// );
+/// ```
+pub fn top_words(s: &str, n: usize) -> Vec<(String, usize)> {
+    let mut ranked: Vec<(String, usize)> = word_frequency(s).into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(n);
+    ranked
+}
+
+/// Count the occurrences of an ASCII byte in a string.
+///
+/// Scans the underlying bytes instead of decoding `char`s, which is faster than
+/// [`count_char`] on large inputs. Only meaningful for ASCII targets (`0..=127`):
+/// non-ASCII bytes are fragments of multi-byte characters, not characters.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::count_byte;
+///
+/// assert_eq!(count_byte("hello world", b'l'), 3);
+/// assert_eq!(count_byte("line 1\nline 2\n", b'\n'), 2);
+/// assert_eq!(count_byte("café", b'e'), 0); // 'é' is not 'e'
+/// ```
+pub fn count_byte(s: &str, target: u8) -> usize {
+    s.bytes().filter(|&b| b == target).count()
+}
+
 /// Reverse a string, preserving UTF-8 character boundaries.
 ///
 /// # Examples
@@ -69,116 +268,2129 @@ pub fn reverse_string(s: &str) -> String {
     s.chars().rev().collect()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Reverse an owned string by character, reusing its allocation.
+///
+/// Produces the same result as [`reverse_string`], but reverses the bytes of
+/// `s` in place and then restores the byte order within each multi-byte
+/// character, so no second buffer is allocated.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::reverse_string_in_place;
+///
+/// let mut s = String::from("hello 👋");
+/// reverse_string_in_place(&mut s);
+/// assert_eq!(s, "👋 olleh");
+/// ```
+pub fn reverse_string_in_place(s: &mut String) {
+    let mut bytes = std::mem::take(s).into_bytes();
+    bytes.reverse();
 
-    // Tests for is_palindrome
-    #[test]
-    fn test_palindrome_simple() {
-        assert!(is_palindrome("racecar"));
-        assert!(is_palindrome("noon"));
-        assert!(is_palindrome("level"));
+    // Each character now ends with its leading byte; flip each one back
+    let mut start = 0;
+    while start < bytes.len() {
+        let mut end = start;
+        while end + 1 < bytes.len() && bytes[end] & 0xC0 == 0x80 {
+            end += 1;
+        }
+        bytes[start..=end].reverse();
+        start = end + 1;
     }
 
-    #[test]
-    fn test_palindrome_with_spaces() {
-        assert!(is_palindrome("A man a plan a canal Panama"));
-        assert!(is_palindrome("race car"));
-        assert!(is_palindrome("taco cat"));
-    }
+    // Every character's bytes are back in order, so this never falls back
+    *s = String::from_utf8(bytes)
+        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+}
 
-    #[test]
-    fn test_palindrome_case_insensitive() {
-        assert!(is_palindrome("RaceCar"));
-        assert!(is_palindrome("Madam"));
-        assert!(is_palindrome("Was it a car or a cat I saw"));
-    }
+/// Count the user-perceived characters (extended grapheme clusters) in a string.
+///
+/// This differs from `len()`, which counts bytes, and from `chars().count()`,
+/// which counts Unicode scalar values: an emoji built from several code points
+/// joined by zero-width joiners, or a letter followed by combining marks,
+/// counts as a single unit.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::display_width;
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("e\u{0301}"), 1); // 'e' + combining acute accent
+/// assert_eq!(display_width("👨‍👩‍👧"), 1); // Family emoji (ZWJ sequence)
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).count()
+}
 
-    #[test]
-    fn test_palindrome_with_punctuation() {
-        assert!(is_palindrome("A man, a plan, a canal: Panama"));
-        assert!(is_palindrome("No 'x' in Nixon"));
-    }
+/// Split a string into its user-perceived characters (extended grapheme clusters).
+///
+/// Each element is a slice of `s`. Emoji joined with zero-width joiners and
+/// letters followed by combining marks stay together as one cluster, so the
+/// length of the result equals [`display_width`].
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::graphemes;
+///
+/// assert_eq!(graphemes("héllo"), vec!["h", "é", "l", "l", "o"]);
+/// assert_eq!(graphemes("e\u{301}x"), vec!["e\u{301}", "x"]);
+/// assert_eq!(graphemes("👨‍👩‍👧!").len(), 2);
+/// ```
+pub fn graphemes(s: &str) -> Vec<&str> {
+    s.graphemes(true).collect()
+}
 
-    #[test]
-    fn test_palindrome_edge_cases() {
-        assert!(is_palindrome(""));
-        assert!(is_palindrome("a"));
-        assert!(is_palindrome(" "));
-    }
+/// Return the `n`th (0-based) extended grapheme cluster of a string.
+///
+/// Returns `None` if the string has `n` or fewer clusters.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::nth_grapheme;
+///
+/// assert_eq!(nth_grapheme("a👍🏽b", 1), Some("👍🏽"));
+/// assert_eq!(nth_grapheme("a👍🏽b", 2), Some("b"));
+/// assert_eq!(nth_grapheme("a👍🏽b", 3), None);
+/// ```
+pub fn nth_grapheme(s: &str, n: usize) -> Option<&str> {
+    s.graphemes(true).nth(n)
+}
 
-    #[test]
-    fn test_not_palindrome() {
-        assert!(!is_palindrome("hello"));
-        assert!(!is_palindrome("world"));
-        assert!(!is_palindrome("Rust programming"));
-    }
+/// Reverse a string while keeping combining marks attached to their base character.
+///
+/// Unlike [`reverse_string`], a base character followed by combining marks
+/// (e.g. `'e'` + U+0301 COMBINING ACUTE ACCENT) is moved as a single unit, so
+/// accents stay on the letter they decorate.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::reverse_preserving_marks;
+///
+/// assert_eq!(reverse_preserving_marks("e\u{0301}abc"), "cbae\u{0301}");
+/// assert_eq!(reverse_preserving_marks("hello"), "olleh");
+/// assert_eq!(reverse_preserving_marks(""), "");
+/// ```
+pub fn reverse_preserving_marks(s: &str) -> String {
+    let mut clusters: Vec<&str> = Vec::new();
+    let mut start = 0;
 
-    // Tests for count_char
-    #[test]
-    fn test_count_char_basic() {
-        assert_eq!(count_char("hello", 'l'), 2);
-        assert_eq!(count_char("hello world", 'o'), 2);
-        assert_eq!(count_char("Mississippi", 's'), 4);
+    for (i, c) in s.char_indices() {
+        if i > 0 && !is_combining_mark(c) {
+            clusters.push(&s[start..i]);
+            start = i;
+        }
+    }
+    if start < s.len() {
+        clusters.push(&s[start..]);
     }
 
-    #[test]
-    fn test_count_char_case_sensitive() {
-        assert_eq!(count_char("Hello", 'H'), 1);
-        assert_eq!(count_char("Hello", 'h'), 0);
-        assert_eq!(count_char("Rust", 'R'), 1);
-        assert_eq!(count_char("Rust", 'r'), 0);
+    clusters.into_iter().rev().collect()
+}
+
+/// Compute the Levenshtein edit distance between two strings.
+///
+/// The distance is the minimum number of single-character insertions, deletions,
+/// or substitutions needed to turn `a` into `b`. Operates on `char`s, not bytes.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::levenshtein;
+///
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// assert_eq!(levenshtein("same", "same"), 0);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b_chars.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
     }
 
-    #[test]
-    fn test_count_char_not_found() {
-        assert_eq!(count_char("hello", 'x'), 0);
-        assert_eq!(count_char("world", 'a'), 0);
+    prev[b_chars.len()]
+}
+
+/// Calculate the Levenshtein distance only if it is at most `max`.
+///
+/// Returns `Some(distance)` when the distance is `<= max` and `None`
+/// otherwise. Only cells within `max` of the diagonal are computed, and the
+/// search stops as soon as every cell in a row exceeds `max`, so this is much
+/// faster than [`levenshtein`] for small thresholds on long strings.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::levenshtein_within;
+///
+/// assert_eq!(levenshtein_within("kitten", "sitting", 3), Some(3));
+/// assert_eq!(levenshtein_within("kitten", "sitting", 2), None);
+/// assert_eq!(levenshtein_within("same", "same", 0), Some(0));
+/// ```
+pub fn levenshtein_within(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (n, m) = (a_chars.len(), b_chars.len());
+    if n.abs_diff(m) > max {
+        return None;
     }
+    // The distance never exceeds the longer length, so a larger threshold
+    // (such as usize::MAX for "no limit") changes nothing and would overflow
+    let max = max.min(n.max(m));
 
-    #[test]
-    fn test_count_char_empty_string() {
-        assert_eq!(count_char("", 'a'), 0);
+    // Any value above max is as good as infinity
+    let over = max + 1;
+    let mut prev: Vec<usize> = (0..=m).map(|j| j.min(over)).collect();
+    let mut curr = vec![over; m + 1];
+
+    for (i, &ca) in a_chars.iter().enumerate() {
+        let row = i + 1;
+        let lo = row.saturating_sub(max).max(1);
+        let hi = row.saturating_add(max).min(m);
+
+        curr[lo - 1] = if lo == 1 { row.min(over) } else { over };
+        let mut row_min = curr[lo - 1];
+        for j in lo..=hi {
+            let cost = if ca == b_chars[j - 1] { 0 } else { 1 };
+            let value = (prev[j - 1] + cost).min(prev[j] + 1).min(curr[j - 1] + 1);
+            curr[j] = value.min(over);
+            row_min = row_min.min(curr[j]);
+        }
+        // The next row may read one cell past this band
+        if hi < m {
+            curr[hi + 1] = over;
+        }
+
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
     }
 
-    #[test]
-    fn test_count_char_all_same() {
-        assert_eq!(count_char("aaaa", 'a'), 4);
-        assert_eq!(count_char("111", '1'), 3);
+    Some(prev[m]).filter(|&d| d <= max)
+}
+
+/// A single step of an [`edit_script`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The char appears in both strings.
+    Keep(char),
+    /// The char is only in the target string.
+    Insert(char),
+    /// The char is only in the source string.
+    Delete(char),
+}
+
+/// Compute the operations that turn `a` into `b`, char by char.
+///
+/// The script is reconstructed by backtracking the [`levenshtein`] table, so
+/// it has a minimal number of edits. A substitution appears as a `Delete` of
+/// the old char followed by an `Insert` of the new one. Applying the script
+/// to `a` (keeping `Keep` chars, adding `Insert` chars, and skipping `Delete`
+/// chars) produces `b`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::{EditOp, edit_script};
+///
+/// assert_eq!(
+///     edit_script("cat", "cut"),
+///     vec![
+///         EditOp::Keep('c'),
+///         EditOp::Delete('a'),
+///         EditOp::Insert('u'),
+///         EditOp::Keep('t'),
+///     ]
+/// );
+/// ```
+pub fn edit_script(a: &str, b: &str) -> Vec<EditOp> {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (n, m) = (a_chars.len(), b_chars.len());
+
+    // dist[i][j] is the distance between the first i chars of a and first j of b
+    let mut dist = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in dist[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] {
+                0
+            } else {
+                1
+            };
+            dist[i][j] = (dist[i - 1][j - 1] + cost)
+                .min(dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1);
+        }
     }
 
-    // Tests for reverse_string
-    #[test]
-    fn test_reverse_string_basic() {
-        assert_eq!(reverse_string("hello"), "olleh");
-        assert_eq!(reverse_string("Rust"), "tsuR");
-        assert_eq!(reverse_string("12345"), "54321");
+    // Walk back from the end, collecting operations in reverse
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a_chars[i - 1] == b_chars[j - 1] && dist[i][j] == dist[i - 1][j - 1] {
+            ops.push(EditOp::Keep(a_chars[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && dist[i][j] == dist[i - 1][j - 1] + 1 {
+            ops.push(EditOp::Insert(b_chars[j - 1]));
+            ops.push(EditOp::Delete(a_chars[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && dist[i][j] == dist[i - 1][j] + 1 {
+            ops.push(EditOp::Delete(a_chars[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(b_chars[j - 1]));
+            j -= 1;
+        }
     }
 
-    #[test]
-    fn test_reverse_string_with_spaces() {
-        assert_eq!(reverse_string("hello world"), "dlrow olleh");
-        assert_eq!(reverse_string("a b c"), "c b a");
+    ops.reverse();
+    ops
+}
+
+/// Compute the Damerau–Levenshtein distance between two strings.
+///
+/// Like [`levenshtein`], but a transposition of two adjacent characters also
+/// counts as a single edit. This implements the *optimal string alignment*
+/// variant, in which no substring is edited more than once (so `"ca"` to
+/// `"abc"` is 3, not 2). Operates on `char`s, not bytes.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::{damerau_levenshtein, levenshtein};
+///
+/// assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+/// assert_eq!(levenshtein("ca", "ac"), 2);
+/// assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (n, m) = (a_chars.len(), b_chars.len());
+
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
     }
 
-    #[test]
-    fn test_reverse_string_edge_cases() {
-        assert_eq!(reverse_string(""), "");
-        assert_eq!(reverse_string("a"), "a");
-        assert_eq!(reverse_string(" "), " ");
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] {
+                0
+            } else {
+                1
+            };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1
+                && j > 1
+                && a_chars[i - 1] == b_chars[j - 2]
+                && a_chars[i - 2] == b_chars[j - 1]
+            {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
     }
 
-    #[test]
-    fn test_reverse_string_palindrome() {
-        let palindrome = "racecar";
-        assert_eq!(reverse_string(palindrome), palindrome);
+    d[n][m]
+}
+
+/// Compute a normalized similarity score between two strings in the range `0.0..=1.0`.
+///
+/// The ratio is `1 - levenshtein(a, b) / max(len_a, len_b)`, where lengths are
+/// counted in `char`s. Two empty strings are considered identical (`1.0`).
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::levenshtein_ratio;
+///
+/// assert_eq!(levenshtein_ratio("rust", "rust"), 1.0);
+/// assert_eq!(levenshtein_ratio("abcd", "wxyz"), 0.0);
+/// assert_eq!(levenshtein_ratio("", ""), 1.0);
+/// assert!(levenshtein_ratio("kitten", "sitting") > 0.5);
+/// ```
+pub fn levenshtein_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
     }
 
-    #[test]
-    fn test_reverse_string_unicode() {
-        assert_eq!(reverse_string("hello 👋"), "👋 olleh");
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// Count the whitespace-separated words in a string that satisfy a predicate.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::count_words_matching;
+///
+/// let text = "The quick brown fox jumps";
+/// assert_eq!(count_words_matching(text, |w| w.len() > 3), 3);
+/// assert_eq!(count_words_matching(text, |w| w.starts_with('T')), 1);
+/// assert_eq!(count_words_matching("", |_| true), 0);
+/// ```
+pub fn count_words_matching<F: Fn(&str) -> bool>(s: &str, pred: F) -> usize {
+    s.split_whitespace().filter(|w| pred(w)).count()
+}
+
+/// Convert each word of a string to Pig Latin.
+///
+/// Words starting with a vowel get `"way"` appended; other words have their
+/// leading consonant cluster moved to the end, followed by `"ay"`. Trailing
+/// punctuation stays at the end of the word, whitespace is preserved as-is,
+/// and tokens that aren't alphabetic words (like numbers) are left unchanged.
+/// Letter case is not adjusted.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::to_pig_latin;
+///
+/// assert_eq!(to_pig_latin("hello world"), "ellohay orldway");
+/// assert_eq!(to_pig_latin("apple"), "appleway");
+/// assert_eq!(to_pig_latin("string theory!"), "ingstray eorythay!");
+/// assert_eq!(to_pig_latin("route 66"), "outeray 66");
+/// ```
+pub fn to_pig_latin(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + s.len() / 2);
+    let mut token = String::new();
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            result.push_str(&pig_latin_word(&token));
+            token.clear();
+            result.push(c);
+        } else {
+            token.push(c);
+        }
+    }
+    result.push_str(&pig_latin_word(&token));
+
+    result
+}
+
+/// Convert a single whitespace-free token to Pig Latin.
+fn pig_latin_word(token: &str) -> String {
+    let word_end = token
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(token.len());
+    let (word, suffix) = token.split_at(word_end);
+
+    if word.is_empty() || suffix.chars().any(|c| c.is_alphabetic()) {
+        return token.to_string();
+    }
+
+    let is_vowel = |c: char| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u');
+    match word.find(is_vowel) {
+        Some(0) => format!("{}way{}", word, suffix),
+        Some(i) => format!("{}{}ay{}", &word[i..], &word[..i], suffix),
+        None => format!("{}ay{}", word, suffix),
+    }
+}
+
+/// Trim whitespace from both ends of a string and report how much was removed.
+///
+/// Returns `(leading, trimmed, trailing)` where `leading` and `trailing` are the
+/// number of whitespace characters (not bytes) removed from each side. For an
+/// all-whitespace input, every character is counted as leading.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::trim_report;
+///
+/// assert_eq!(trim_report("  hi "), (2, "hi", 1));
+/// assert_eq!(trim_report("hi"), (0, "hi", 0));
+/// assert_eq!(trim_report("   "), (3, "", 0));
+/// ```
+pub fn trim_report(s: &str) -> (usize, &str, usize) {
+    let start_trimmed = s.trim_start();
+    let trimmed = start_trimmed.trim_end();
+
+    let leading = s[..s.len() - start_trimmed.len()].chars().count();
+    let trailing = start_trimmed[trimmed.len()..].chars().count();
+
+    (leading, trimmed, trailing)
+}
+
+/// Remove ANSI escape sequences (such as terminal colors) from a string.
+///
+/// Strips CSI sequences: `ESC [`, followed by parameter bytes, terminated by a
+/// final byte in the range `@`–`~` (typically a letter, e.g. `m` for colors or
+/// `H` for cursor movement). An unterminated sequence at the end of the input
+/// is dropped. Other text is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::strip_ansi_codes;
+///
+/// assert_eq!(strip_ansi_codes("\x1b[31mred\x1b[0m"), "red");
+/// assert_eq!(strip_ansi_codes("\x1b[1;32mOK\x1b[0m done"), "OK done");
+/// assert_eq!(strip_ansi_codes("plain text"), "plain text");
+/// ```
+pub fn strip_ansi_codes(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            Some(_) => result.push(c),
+            None => {}
+        }
+    }
+
+    result
+}
+
+/// Convert all line endings in a string to a single style.
+///
+/// Supported styles:
+/// - "lf" (`\n`)
+/// - "crlf" (`\r\n`)
+///
+/// Every `\r\n`, lone `\r`, and lone `\n` is replaced by the chosen ending.
+/// An unknown style returns the input unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::normalize_newlines;
+///
+/// assert_eq!(normalize_newlines("a\r\nb\rc\n", "lf"), "a\nb\nc\n");
+/// assert_eq!(normalize_newlines("a\nb", "crlf"), "a\r\nb");
+/// assert_eq!(normalize_newlines("a\r\nb", "unknown"), "a\r\nb");
+/// ```
+pub fn normalize_newlines(s: &str, style: &str) -> String {
+    let newline = match style {
+        "lf" => "\n",
+        "crlf" => "\r\n",
+        _ => return s.to_string(),
+    };
+
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                result.push_str(newline);
+            }
+            '\n' => result.push_str(newline),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Encode the UTF-8 bytes of a string as lowercase hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::to_hex;
+///
+/// assert_eq!(to_hex("Hi"), "4869");
+/// assert_eq!(to_hex("é"), "c3a9");
+/// assert_eq!(to_hex(""), "");
+/// ```
+pub fn to_hex(s: &str) -> String {
+    s.bytes().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode a hexadecimal string back into a UTF-8 string.
+///
+/// Accepts both lowercase and uppercase digits. Returns `None` if the input has
+/// an odd length, contains non-hex characters, or decodes to invalid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::from_hex;
+///
+/// assert_eq!(from_hex("4869"), Some("Hi".to_string()));
+/// assert_eq!(from_hex("C3A9"), Some("é".to_string()));
+/// assert_eq!(from_hex("zz"), None);
+/// assert_eq!(from_hex("486"), None);
+/// ```
+pub fn from_hex(hex: &str) -> Option<String> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let bytes = hex
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Count the lines in a string.
+///
+/// Lines are separated by `\n` or `\r\n`. A trailing newline does not start
+/// an extra empty line, and an empty string has no lines.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::line_count;
+///
+/// assert_eq!(line_count("a\nb\nc"), 3);
+/// assert_eq!(line_count("a\nb\nc\n"), 3);
+/// assert_eq!(line_count(""), 0);
+/// ```
+pub fn line_count(s: &str) -> usize {
+    s.lines().count()
+}
+
+/// Split a string on whitespace, returning each word with its starting byte offset.
+///
+/// Offsets index into the original string, so `&s[offset..offset + word.len()]`
+/// is always the word itself.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::tokenize_with_positions;
+///
+/// assert_eq!(tokenize_with_positions("ab cd"), vec![(0, "ab"), (3, "cd")]);
+/// assert_eq!(tokenize_with_positions("  hi"), vec![(2, "hi")]);
+/// assert!(tokenize_with_positions("   ").is_empty());
+/// ```
+pub fn tokenize_with_positions(s: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(begin)) => {
+                tokens.push((begin, &s[begin..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin, &s[begin..]));
+    }
+    tokens
+}
+
+/// Fully case fold a character. Lowercasing, uppercasing, and lowercasing
+/// again maps every case variant to one form, e.g. 'ß', 'ẞ' and "SS" all
+/// become "ss".
+fn fold_case(c: char) -> impl Iterator<Item = char> {
+    c.to_lowercase()
+        .flat_map(char::to_uppercase)
+        .flat_map(char::to_lowercase)
+}
+
+/// Join string slices with a separator using a single allocation.
+///
+/// The exact final length (all parts plus the separators between them) is
+/// computed up front, so the result is built without any reallocation. Useful
+/// when joining thousands of fragments.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::join_with_capacity;
+///
+/// let joined = join_with_capacity(&["a", "b", "c"], ", ");
+/// assert_eq!(joined, "a, b, c");
+/// assert_eq!(joined.capacity(), joined.len());
+///
+/// assert_eq!(join_with_capacity(&[], "-"), "");
+/// ```
+pub fn join_with_capacity(parts: &[&str], sep: &str) -> String {
+    let parts_len: usize = parts.iter().map(|p| p.len()).sum();
+    let sep_len = sep.len() * parts.len().saturating_sub(1);
+    let mut result = String::with_capacity(parts_len + sep_len);
+
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            result.push_str(sep);
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+/// Center a string within `width` characters, padding both sides with `fill`.
+///
+/// When the padding can't be split evenly, the extra `fill` goes on the right.
+/// Width is measured in chars. A string already at least `width` chars long
+/// is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::center;
+///
+/// assert_eq!(center("hi", 6, '-'), "--hi--");
+/// assert_eq!(center("hi", 5, '-'), "-hi--");
+/// assert_eq!(center("hello", 3, '*'), "hello");
+/// ```
+pub fn center(s: &str, width: usize, fill: char) -> String {
+    let len = s.chars().count();
+    if len >= width {
+        return s.to_string();
+    }
+
+    let padding = width - len;
+    let left = padding / 2;
+    let right = padding - left;
+
+    let mut result = String::with_capacity(s.len() + padding * fill.len_utf8());
+    result.extend(std::iter::repeat_n(fill, left));
+    result.push_str(s);
+    result.extend(std::iter::repeat_n(fill, right));
+    result
+}
+
+/// Replace each tab with spaces up to the next multiple of `tab_width`.
+///
+/// Columns are counted in chars and reset after every newline, so each line
+/// is expanded independently. A `tab_width` of 0 removes tabs entirely.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::expand_tabs;
+///
+/// assert_eq!(expand_tabs("a\tb", 4), "a   b");
+/// assert_eq!(expand_tabs("\tx\nab\ty", 4), "    x\nab  y");
+/// ```
+pub fn expand_tabs(s: &str, tab_width: usize) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut column = 0;
+
+    for c in s.chars() {
+        match c {
+            '\t' => {
+                if tab_width > 0 {
+                    let spaces = tab_width - column % tab_width;
+                    result.extend(std::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                }
+            }
+            '\n' => {
+                result.push(c);
+                column = 0;
+            }
+            _ => {
+                result.push(c);
+                column += 1;
+            }
+        }
+    }
+    result
+}
+
+/// Build an acronym from the first letter of each whitespace-separated word.
+///
+/// Letters are uppercased. Words that don't start with a letter (such as
+/// numbers or symbols) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::acronym;
+///
+/// assert_eq!(acronym("portable document format"), "PDF");
+/// assert_eq!(acronym("Graphics Interchange format"), "GIF");
+/// assert_eq!(acronym("3 letter acronym"), "LA");
+/// ```
+pub fn acronym(s: &str) -> String {
+    s.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Find the longest substring that reads the same forwards and backwards.
+///
+/// Expands around every possible center, comparing chars exactly (case and
+/// punctuation matter, unlike [`is_palindrome`]). When several palindromes
+/// share the longest length, the first one is returned. An empty input
+/// returns an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::longest_palindromic_substring;
+///
+/// assert_eq!(longest_palindromic_substring("babad"), "bab");
+/// assert_eq!(longest_palindromic_substring("cbbd"), "bb");
+/// assert_eq!(longest_palindromic_substring(""), "");
+/// ```
+pub fn longest_palindromic_substring(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let (mut best_start, mut best_len) = (0, 0);
+
+    // Expand from the center between `left` and `right`, returning (start, len)
+    let expand = |mut left: usize, mut right: usize| {
+        while left > 0 && right < chars.len() && chars[left - 1] == chars[right] {
+            left -= 1;
+            right += 1;
+        }
+        (left, right - left)
+    };
+
+    for i in 0..chars.len() {
+        // Odd length centered on i, then even length centered between i and i + 1
+        for (start, len) in [expand(i, i + 1), expand(i + 1, i + 1)] {
+            if len > best_len {
+                (best_start, best_len) = (start, len);
+            }
+        }
+    }
+
+    chars[best_start..best_start + best_len].iter().collect()
+}
+
+/// Split a string on a delimiter, keeping each delimiter as its own element.
+///
+/// Empty segments (from leading, trailing, or consecutive delimiters) are
+/// dropped, so concatenating the result always gives back `s`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::split_keep_delimiter;
+///
+/// assert_eq!(split_keep_delimiter("a,b,c", ','), vec!["a", ",", "b", ",", "c"]);
+/// assert_eq!(split_keep_delimiter(",a,,", ','), vec![",", "a", ",", ","]);
+/// ```
+pub fn split_keep_delimiter(s: &str, delim: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    for (index, matched) in s.match_indices(delim) {
+        if index > start {
+            parts.push(&s[start..index]);
+        }
+        parts.push(matched);
+        start = index + matched.len();
+    }
+    if start < s.len() {
+        parts.push(&s[start..]);
+    }
+    parts
+}
+
+/// Wrap text into lines of at most `width` chars, hyphenating over-long words.
+///
+/// Words are packed greedily, separated by single spaces. A word longer than
+/// `width` starts on a new line and is split into pieces of `width - 1` chars
+/// followed by '-'; its last piece can share a line with the following words.
+/// Hyphenating needs `width >= 2`, so for smaller widths each word is placed
+/// on its own line unsplit.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::wrap_text_hyphenate;
+///
+/// assert_eq!(
+///     wrap_text_hyphenate("the quick brown fox", 10),
+///     vec!["the quick", "brown fox"]
+/// );
+/// assert_eq!(
+///     wrap_text_hyphenate("an extraordinarily long word", 8),
+///     vec!["an", "extraor-", "dinarily", "long", "word"]
+/// );
+/// ```
+pub fn wrap_text_hyphenate(s: &str, width: usize) -> Vec<String> {
+    if width < 2 {
+        return s.split_whitespace().map(str::to_string).collect();
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in s.split_whitespace() {
+        let mut chars: Vec<char> = word.chars().collect();
+
+        if chars.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            while chars.len() > width {
+                let mut piece: String = chars.drain(..width - 1).collect();
+                piece.push('-');
+                lines.push(piece);
+            }
+            current = chars.iter().collect();
+            current_len = chars.len();
+            continue;
+        }
+
+        if current.is_empty() {
+            current.push_str(word);
+            current_len = chars.len();
+        } else if current_len + 1 + chars.len() <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_len += 1 + chars.len();
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+            current_len = chars.len();
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Check whether the brackets `()`, `[]` and `{}` in a string are balanced.
+///
+/// Every closing bracket must match the most recent unclosed opening bracket
+/// of the same kind, and no bracket may be left open. All other characters
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use rust_utils_lib::is_balanced;
+///
+/// assert!(is_balanced("a(b[c]d)e"));
+/// assert!(is_balanced(""));
+/// assert!(!is_balanced("([)]"));
+/// assert!(!is_balanced(")("));
+/// ```
+pub fn is_balanced(s: &str) -> bool {
+    let mut open = Vec::new();
+
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' => open.push(c),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if open.pop() != Some(expected) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    open.is_empty()
+}
+
+/// Check if a character belongs to one of the Unicode combining mark blocks.
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests for is_palindrome
+    #[test]
+    fn test_palindrome_simple() {
+        assert!(is_palindrome("racecar"));
+        assert!(is_palindrome("noon"));
+        assert!(is_palindrome("level"));
+    }
+
+    #[test]
+    fn test_palindrome_with_spaces() {
+        assert!(is_palindrome("A man a plan a canal Panama"));
+        assert!(is_palindrome("race car"));
+        assert!(is_palindrome("taco cat"));
+    }
+
+    #[test]
+    fn test_palindrome_case_insensitive() {
+        assert!(is_palindrome("RaceCar"));
+        assert!(is_palindrome("Madam"));
+        assert!(is_palindrome("Was it a car or a cat I saw"));
+    }
+
+    #[test]
+    fn test_palindrome_with_punctuation() {
+        assert!(is_palindrome("A man, a plan, a canal: Panama"));
+        assert!(is_palindrome("No 'x' in Nixon"));
+    }
+
+    #[test]
+    fn test_palindrome_edge_cases() {
+        assert!(is_palindrome(""));
+        assert!(is_palindrome("a"));
+        assert!(is_palindrome(" "));
+    }
+
+    #[test]
+    fn test_not_palindrome() {
+        assert!(!is_palindrome("hello"));
+        assert!(!is_palindrome("world"));
+        assert!(!is_palindrome("Rust programming"));
+    }
+
+    #[test]
+    fn test_palindrome_multi_char_lowercase() {
+        // 'İ' lowercases to "i\u{0307}"; only its first char used to be kept,
+        // which made "İbi" look like "ibi"
+        assert!(!is_palindrome("İbi"));
+        assert!(is_palindrome("İbİ"));
+        assert!(is_palindrome("İ"));
+    }
+
+    // Tests for is_palindrome_with
+    #[test]
+    fn test_palindrome_with_letters_only() {
+        // Digits are ignored when only letters are kept
+        assert!(is_palindrome_with("ab1ba2", |c| c.is_alphabetic()));
+        assert!(!is_palindrome_with("ab1ba2", |c| c.is_alphanumeric()));
+    }
+
+    #[test]
+    fn test_palindrome_with_punctuation_kept() {
+        let s = "a,b.a";
+        assert!(is_palindrome_with(s, |c| c.is_alphanumeric()));
+        assert!(!is_palindrome_with(s, |c| !c.is_whitespace()));
+        assert!(is_palindrome_with("a,b,a", |c| !c.is_whitespace()));
+    }
+
+    #[test]
+    fn test_palindrome_with_matches_is_palindrome() {
+        for s in ["racecar", "A man, a plan, a canal: Panama", "hello", ""] {
+            assert_eq!(
+                is_palindrome_with(s, |c| c.is_alphanumeric()),
+                is_palindrome(s)
+            );
+        }
+    }
+
+    // Tests for count_char
+    #[test]
+    fn test_count_char_basic() {
+        assert_eq!(count_char("hello", 'l'), 2);
+        assert_eq!(count_char("hello world", 'o'), 2);
+        assert_eq!(count_char("Mississippi", 's'), 4);
+    }
+
+    #[test]
+    fn test_count_char_case_sensitive() {
+        assert_eq!(count_char("Hello", 'H'), 1);
+        assert_eq!(count_char("Hello", 'h'), 0);
+        assert_eq!(count_char("Rust", 'R'), 1);
+        assert_eq!(count_char("Rust", 'r'), 0);
+    }
+
+    #[test]
+    fn test_count_char_not_found() {
+        assert_eq!(count_char("hello", 'x'), 0);
+        assert_eq!(count_char("world", 'a'), 0);
+    }
+
+    #[test]
+    fn test_count_char_empty_string() {
+        assert_eq!(count_char("", 'a'), 0);
+    }
+
+    #[test]
+    fn test_count_char_all_same() {
+        assert_eq!(count_char("aaaa", 'a'), 4);
+        assert_eq!(count_char("111", '1'), 3);
+    }
+
+    // Tests for most_common_chars
+    #[test]
+    fn test_most_common_chars_basic() {
+        assert_eq!(most_common_chars("aaabbc", 2), vec![('a', 3), ('b', 2)]);
+        assert_eq!(
+            most_common_chars("aaabbc", 10),
+            vec![('a', 3), ('b', 2), ('c', 1)]
+        );
+    }
+
+    #[test]
+    fn test_most_common_chars_zero_and_empty() {
+        assert!(most_common_chars("aaabbc", 0).is_empty());
+        assert!(most_common_chars("", 3).is_empty());
+    }
+
+    #[test]
+    fn test_most_common_chars_ties() {
+        // Equal counts are ordered by character
+        assert_eq!(
+            most_common_chars("cba", 3),
+            vec![('a', 1), ('b', 1), ('c', 1)]
+        );
+        assert_eq!(most_common_chars("zzyyx", 2), vec![('y', 2), ('z', 2)]);
+    }
+
+    // Tests for word_frequency
+    #[test]
+    fn test_word_frequency_repeated_words() {
+        let freq = word_frequency("one fish two fish red fish blue fish");
+        assert_eq!(freq["fish"], 4);
+        assert_eq!(freq["one"], 1);
+        assert_eq!(freq.len(), 5);
+    }
+
+    #[test]
+    fn test_word_frequency_case_and_punctuation() {
+        let freq = word_frequency("Hello, hello! HELLO? world.");
+        assert_eq!(freq["hello"], 3);
+        assert_eq!(freq["world"], 1);
+        assert_eq!(freq.len(), 2);
+    }
+
+    #[test]
+    fn test_word_frequency_empty() {
+        assert!(word_frequency("").is_empty());
+        assert!(word_frequency(" ,.! ").is_empty());
+    }
+
+    // Tests for top_words
+    #[test]
+    fn test_top_words_ordering() {
+        let text = "b a c b a b";
+        assert_eq!(
+            top_words(text, 2),
+            vec![("b".to_string(), 3), ("a".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_top_words_ties_are_alphabetical() {
+        assert_eq!(
+            top_words("zeta alpha mid", 3),
+            vec![
+                ("alpha".to_string(), 1),
+                ("mid".to_string(), 1),
+                ("zeta".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_top_words_empty_and_zero() {
+        assert!(top_words("", 5).is_empty());
+        assert!(top_words("some words here", 0).is_empty());
+    }
+
+    // Tests for count_byte
+    #[test]
+    fn test_count_byte_basic() {
+        assert_eq!(count_byte("hello", b'l'), 2);
+        assert_eq!(count_byte("Mississippi", b's'), 4);
+        assert_eq!(count_byte("", b'a'), 0);
+    }
+
+    #[test]
+    fn test_count_byte_matches_count_char() {
+        let text = "The quick brown fox jumps over the lazy dog\n".repeat(1000);
+        for c in ['o', 'e', ' ', '\n', 'z', 'X'] {
+            assert_eq!(count_byte(&text, c as u8), count_char(&text, c));
+        }
+    }
+
+    #[test]
+    fn test_count_byte_ignores_multibyte_chars() {
+        // 'é' and '👋' are multi-byte and never match an ASCII target
+        assert_eq!(count_byte("é👋é", b'e'), 0);
+        assert_eq!(count_byte("a é a", b'a'), 2);
+    }
+
+    // Tests for reverse_string
+    #[test]
+    fn test_reverse_string_basic() {
+        assert_eq!(reverse_string("hello"), "olleh");
+        assert_eq!(reverse_string("Rust"), "tsuR");
+        assert_eq!(reverse_string("12345"), "54321");
+    }
+
+    #[test]
+    fn test_reverse_string_with_spaces() {
+        assert_eq!(reverse_string("hello world"), "dlrow olleh");
+        assert_eq!(reverse_string("a b c"), "c b a");
+    }
+
+    #[test]
+    fn test_reverse_string_edge_cases() {
+        assert_eq!(reverse_string(""), "");
+        assert_eq!(reverse_string("a"), "a");
+        assert_eq!(reverse_string(" "), " ");
+    }
+
+    #[test]
+    fn test_reverse_string_palindrome() {
+        let palindrome = "racecar";
+        assert_eq!(reverse_string(palindrome), palindrome);
+    }
+
+    #[test]
+    fn test_reverse_string_unicode() {
+        assert_eq!(reverse_string("hello 👋"), "👋 olleh");
         assert_eq!(reverse_string("café"), "éfac");
         assert_eq!(reverse_string("日本"), "本日");
     }
+
+    // Tests for reverse_preserving_marks
+    #[test]
+    fn test_reverse_preserving_marks_acute_accent() {
+        assert_eq!(reverse_preserving_marks("e\u{0301}abc"), "cbae\u{0301}");
+        assert_eq!(reverse_preserving_marks("abce\u{0301}"), "e\u{0301}cba");
+    }
+
+    #[test]
+    fn test_reverse_preserving_marks_multiple_marks() {
+        // 'a' with a grave accent and a combining tilde stays together
+        assert_eq!(
+            reverse_preserving_marks("xa\u{0300}\u{0303}y"),
+            "ya\u{0300}\u{0303}x"
+        );
+    }
+
+    #[test]
+    fn test_reverse_preserving_marks_differs_from_reverse_string() {
+        let s = "e\u{0301}x";
+        assert_eq!(reverse_string(s), "x\u{0301}e");
+        assert_eq!(reverse_preserving_marks(s), "xe\u{0301}");
+    }
+
+    #[test]
+    fn test_reverse_preserving_marks_edge_cases() {
+        assert_eq!(reverse_preserving_marks(""), "");
+        assert_eq!(reverse_preserving_marks("a"), "a");
+        assert_eq!(reverse_preserving_marks("hello"), "olleh");
+        // A leading mark with no base character is kept as its own unit
+        assert_eq!(reverse_preserving_marks("\u{0301}ab"), "ba\u{0301}");
+    }
+
+    // Tests for levenshtein
+    #[test]
+    fn test_levenshtein_basic() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("abc", "abc"), 0);
+    }
+
+    #[test]
+    fn test_levenshtein_empty() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn test_levenshtein_unicode() {
+        assert_eq!(levenshtein("café", "cafe"), 1);
+        assert_eq!(levenshtein("日本", "日本語"), 1);
+    }
+
+    // Tests for levenshtein_ratio
+    #[test]
+    fn test_levenshtein_ratio_identical() {
+        assert_eq!(levenshtein_ratio("hello", "hello"), 1.0);
+        assert_eq!(levenshtein_ratio("a", "a"), 1.0);
+    }
+
+    #[test]
+    fn test_levenshtein_ratio_completely_different() {
+        assert_eq!(levenshtein_ratio("abc", "xyz"), 0.0);
+        assert_eq!(levenshtein_ratio("a", ""), 0.0);
+    }
+
+    #[test]
+    fn test_levenshtein_ratio_empty_strings() {
+        assert_eq!(levenshtein_ratio("", ""), 1.0);
+    }
+
+    #[test]
+    fn test_levenshtein_ratio_partial() {
+        // One substitution out of four chars
+        assert_eq!(levenshtein_ratio("rust", "bust"), 0.75);
+    }
+
+    // Tests for damerau_levenshtein
+    #[test]
+    fn test_damerau_levenshtein_transposition() {
+        assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+        assert_eq!(levenshtein("ca", "ac"), 2);
+        assert_eq!(damerau_levenshtein("teh", "the"), 1);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_agrees_on_insertion() {
+        assert_eq!(damerau_levenshtein("cat", "cats"), 1);
+        assert_eq!(
+            damerau_levenshtein("cat", "cats"),
+            levenshtein("cat", "cats")
+        );
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_optimal_string_alignment() {
+        // OSA does not edit a transposed pair again
+        assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_empty() {
+        assert_eq!(damerau_levenshtein("", ""), 0);
+        assert_eq!(damerau_levenshtein("abc", ""), 3);
+        assert_eq!(damerau_levenshtein("", "ab"), 2);
+    }
+
+    // Tests for count_words_matching
+    #[test]
+    fn test_count_words_matching_length() {
+        assert_eq!(
+            count_words_matching("a bb ccc dddd eeeee", |w| w.len() > 3),
+            2
+        );
+        assert_eq!(count_words_matching("hi there", |w| w.len() > 10), 0);
+    }
+
+    #[test]
+    fn test_count_words_matching_capitalized() {
+        let text = "Alice met Bob in paris";
+        let capitalized = |w: &str| w.chars().next().is_some_and(|c| c.is_uppercase());
+        assert_eq!(count_words_matching(text, capitalized), 2);
+    }
+
+    #[test]
+    fn test_count_words_matching_irregular_whitespace() {
+        assert_eq!(count_words_matching("  one\ttwo\n three  ", |_| true), 3);
+    }
+
+    #[test]
+    fn test_count_words_matching_empty() {
+        assert_eq!(count_words_matching("", |_| true), 0);
+        assert_eq!(count_words_matching("   ", |_| true), 0);
+    }
+
+    // Tests for display_width
+    #[test]
+    fn test_display_width_ascii() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("hello world"), 11);
+    }
+
+    #[test]
+    fn test_display_width_zwj_emoji() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(display_width(family), 1);
+        assert_eq!(family.chars().count(), 5);
+        assert_eq!(family.len(), 18);
+    }
+
+    #[test]
+    fn test_display_width_combining_marks() {
+        let accented = "cafe\u{0301}";
+        assert_eq!(display_width(accented), 4);
+        assert_eq!(accented.chars().count(), 5);
+        assert_eq!(accented.len(), 6);
+    }
+
+    // Tests for to_pig_latin
+    #[test]
+    fn test_pig_latin_vowel_initial() {
+        assert_eq!(to_pig_latin("apple"), "appleway");
+        assert_eq!(to_pig_latin("egg"), "eggway");
+        assert_eq!(to_pig_latin("Ice"), "Iceway");
+    }
+
+    #[test]
+    fn test_pig_latin_consonant_clusters() {
+        assert_eq!(to_pig_latin("hello"), "ellohay");
+        assert_eq!(to_pig_latin("string"), "ingstray");
+        assert_eq!(to_pig_latin("chair"), "airchay");
+        // No vowels at all: the whole word is the cluster
+        assert_eq!(to_pig_latin("rhythm"), "rhythmay");
+    }
+
+    #[test]
+    fn test_pig_latin_punctuation() {
+        assert_eq!(to_pig_latin("hello, world!"), "ellohay, orldway!");
+        assert_eq!(to_pig_latin("!?"), "!?");
+        assert_eq!(to_pig_latin("42"), "42");
+        assert_eq!(to_pig_latin("e-mail"), "e-mail");
+    }
+
+    #[test]
+    fn test_pig_latin_preserves_spacing() {
+        assert_eq!(to_pig_latin("  hello   world "), "  ellohay   orldway ");
+        assert_eq!(to_pig_latin("a\tb\nc"), "away\tbay\ncay");
+        assert_eq!(to_pig_latin(""), "");
+    }
+
+    // Tests for trim_report
+    #[test]
+    fn test_trim_report_basic() {
+        assert_eq!(trim_report("  hi "), (2, "hi", 1));
+        assert_eq!(trim_report("\t\nvalue  \n"), (2, "value", 3));
+    }
+
+    #[test]
+    fn test_trim_report_no_whitespace() {
+        assert_eq!(trim_report("hi"), (0, "hi", 0));
+        assert_eq!(trim_report("a b"), (0, "a b", 0));
+    }
+
+    #[test]
+    fn test_trim_report_all_whitespace() {
+        assert_eq!(trim_report("    "), (4, "", 0));
+        assert_eq!(trim_report(""), (0, "", 0));
+    }
+
+    #[test]
+    fn test_trim_report_counts_chars_not_bytes() {
+        // U+3000 IDEOGRAPHIC SPACE is 3 bytes but one character
+        assert_eq!(trim_report("\u{3000}x\u{3000}\u{3000}"), (1, "x", 2));
+    }
+
+    // Tests for strip_ansi_codes
+    #[test]
+    fn test_strip_ansi_color_codes() {
+        assert_eq!(strip_ansi_codes("\x1b[31mred\x1b[0m"), "red");
+        assert_eq!(
+            strip_ansi_codes("\x1b[1;4;38;5;208mfancy\x1b[0m text"),
+            "fancy text"
+        );
+    }
+
+    #[test]
+    fn test_strip_ansi_cursor_movement() {
+        assert_eq!(strip_ansi_codes("\x1b[2J\x1b[10;20Hhello"), "hello");
+        assert_eq!(strip_ansi_codes("up\x1b[3Adown\x1b[K"), "updown");
+    }
+
+    #[test]
+    fn test_strip_ansi_no_escapes() {
+        assert_eq!(strip_ansi_codes("no escapes [here]"), "no escapes [here]");
+        assert_eq!(strip_ansi_codes(""), "");
+        assert_eq!(strip_ansi_codes("café 👋"), "café 👋");
+    }
+
+    #[test]
+    fn test_strip_ansi_incomplete_sequence() {
+        assert_eq!(strip_ansi_codes("text\x1b[31"), "text");
+        assert_eq!(strip_ansi_codes("text\x1b["), "text");
+        assert_eq!(strip_ansi_codes("text\x1b"), "text");
+    }
+
+    // Tests for normalize_newlines
+    #[test]
+    fn test_normalize_newlines_to_lf() {
+        assert_eq!(
+            normalize_newlines("one\r\ntwo\rthree\nfour", "lf"),
+            "one\ntwo\nthree\nfour"
+        );
+    }
+
+    #[test]
+    fn test_normalize_newlines_to_crlf() {
+        assert_eq!(
+            normalize_newlines("one\r\ntwo\rthree\nfour", "crlf"),
+            "one\r\ntwo\r\nthree\r\nfour"
+        );
+    }
+
+    #[test]
+    fn test_normalize_newlines_idempotent() {
+        let mixed = "a\r\n\r\nb\n\rc\r";
+        for style in ["lf", "crlf"] {
+            let once = normalize_newlines(mixed, style);
+            assert_eq!(normalize_newlines(&once, style), once);
+        }
+    }
+
+    #[test]
+    fn test_normalize_newlines_edge_cases() {
+        assert_eq!(normalize_newlines("", "lf"), "");
+        assert_eq!(normalize_newlines("no newlines", "crlf"), "no newlines");
+        assert_eq!(normalize_newlines("a\r\nb", "LF"), "a\r\nb"); // Unknown style
+    }
+
+    // Tests for to_hex and from_hex
+    #[test]
+    fn test_to_hex_basic() {
+        assert_eq!(to_hex("abc"), "616263");
+        assert_eq!(to_hex("\n"), "0a");
+        assert_eq!(to_hex(""), "");
+    }
+
+    #[test]
+    fn test_hex_round_trip_unicode() {
+        for s in ["hello", "café", "日本語", "👋🌍", ""] {
+            assert_eq!(from_hex(&to_hex(s)), Some(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_from_hex_invalid_chars() {
+        assert_eq!(from_hex("zz"), None);
+        assert_eq!(from_hex("4g"), None);
+        assert_eq!(from_hex("+1"), None);
+    }
+
+    #[test]
+    fn test_from_hex_odd_length() {
+        assert_eq!(from_hex("a"), None);
+        assert_eq!(from_hex("616"), None);
+    }
+
+    #[test]
+    fn test_from_hex_invalid_utf8() {
+        // A lone continuation byte is not valid UTF-8
+        assert_eq!(from_hex("80"), None);
+    }
+
+    #[test]
+    fn test_from_hex_non_ascii_input() {
+        // "é" is two bytes but not hex digits
+        assert_eq!(from_hex("é"), None);
+    }
+
+    // Tests for line_count
+    #[test]
+    fn test_line_count_basic() {
+        assert_eq!(line_count("a\nb\nc"), 3);
+        assert_eq!(line_count("single line"), 1);
+    }
+
+    #[test]
+    fn test_line_count_trailing_newline() {
+        assert_eq!(line_count("a\nb\nc\n"), 3);
+        assert_eq!(line_count("a\r\nb\r\n"), 2);
+    }
+
+    #[test]
+    fn test_line_count_empty() {
+        assert_eq!(line_count(""), 0);
+        // A lone newline is one empty line
+        assert_eq!(line_count("\n"), 1);
+    }
+
+    // Tests for tokenize_with_positions
+    #[test]
+    fn test_tokenize_with_positions_basic() {
+        assert_eq!(tokenize_with_positions("ab cd"), vec![(0, "ab"), (3, "cd")]);
+    }
+
+    #[test]
+    fn test_tokenize_with_positions_leading_whitespace() {
+        assert_eq!(
+            tokenize_with_positions("\t  foo bar"),
+            vec![(3, "foo"), (7, "bar")]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_with_positions_multi_space_gap() {
+        assert_eq!(
+            tokenize_with_positions("one    two\n\nthree "),
+            vec![(0, "one"), (7, "two"), (12, "three")]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_with_positions_multibyte() {
+        // Offsets are in bytes, not chars
+        assert_eq!(
+            tokenize_with_positions("café au lait"),
+            vec![(0, "café"), (6, "au"), (9, "lait")]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_with_positions_empty() {
+        assert!(tokenize_with_positions("").is_empty());
+        assert!(tokenize_with_positions(" \t\n").is_empty());
+    }
+
+    // Tests for count_char_casefold
+    #[test]
+    fn test_count_char_casefold_ascii() {
+        assert_eq!(count_char_casefold("Hello World", 'L'), 3);
+        assert_eq!(count_char_casefold("Rust", 'r'), 1);
+        assert_eq!(count_char_casefold("", 'a'), 0);
+    }
+
+    #[test]
+    fn test_count_char_casefold_sharp_s() {
+        // 'ß' uppercases to "SS", which is longer than the original
+        assert_eq!(count_char_casefold("Straße", 'ß'), 1);
+        assert_eq!(count_char_casefold("STRASSE", 'ß'), 1);
+        assert_eq!(count_char_casefold("STRAẞE", 'ß'), 1);
+        // "ss" may only match whole characters, not half of the fold of 'ß'
+        assert_eq!(count_char_casefold("sß", 'ß'), 1);
+        assert_eq!(count_char_casefold("ß", 's'), 0);
+    }
+
+    #[test]
+    fn test_count_char_casefold_dotted_capital_i() {
+        // 'İ' lowercases to "i\u{0307}", two characters
+        assert_eq!(count_char_casefold("İİ", 'İ'), 2);
+        assert_eq!(count_char_casefold("i\u{0307}stanbul", 'İ'), 1);
+        // A plain 'i' is not a dotted capital I
+        assert_eq!(count_char_casefold("istanbul", 'İ'), 0);
+        // The 'i' in the fold of 'İ' is not an 'i' of its own
+        assert_eq!(count_char_casefold("İ", 'i'), 0);
+        assert_eq!(count_char_casefold("İi", 'i'), 1);
+    }
+
+    #[test]
+    fn test_fold_case() {
+        assert_eq!(fold_case('A').collect::<String>(), "a");
+        assert_eq!(fold_case('ß').collect::<String>(), "ss");
+        assert_eq!(fold_case('İ').collect::<String>(), "i\u{0307}");
+    }
+
+    // Tests for join_with_capacity
+    #[test]
+    fn test_join_with_capacity_matches_join() {
+        let cases: [&[&str]; 4] = [&["a", "b", "c"], &["solo"], &[], &["", "x", ""]];
+        for parts in cases {
+            for sep in ["", ", ", "→"] {
+                assert_eq!(join_with_capacity(parts, sep), parts.join(sep));
+            }
+        }
+    }
+
+    #[test]
+    fn test_join_with_capacity_exact_allocation() {
+        let fragments: Vec<String> = (0..5000).map(|i| format!("item{}", i)).collect();
+        let parts: Vec<&str> = fragments.iter().map(String::as_str).collect();
+
+        let joined = join_with_capacity(&parts, ", ");
+        // Capacity equal to length means the buffer was never grown
+        assert_eq!(joined.capacity(), joined.len());
+        assert_eq!(joined, parts.join(", "));
+    }
+
+    // Tests for count_chars
+    #[test]
+    fn test_count_chars_multiple_targets() {
+        let counts = count_chars("hello world", &['l', 'o']);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&'l'], 3);
+        assert_eq!(counts[&'o'], 2);
+    }
+
+    #[test]
+    fn test_count_chars_absent_targets() {
+        let counts = count_chars("hello world", &['z', 'H']);
+        assert_eq!(counts[&'z'], 0);
+        assert_eq!(counts[&'H'], 0);
+
+        let counts = count_chars("", &['a']);
+        assert_eq!(counts[&'a'], 0);
+    }
+
+    #[test]
+    fn test_count_chars_no_targets_and_duplicates() {
+        assert!(count_chars("hello", &[]).is_empty());
+
+        let counts = count_chars("hello", &['l', 'l']);
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[&'l'], 2);
+    }
+
+    // Tests for reverse_string_in_place
+    #[test]
+    fn test_reverse_string_in_place_matches_reverse_string() {
+        for original in [
+            "hello",
+            "Rust",
+            "",
+            "a",
+            "hello 👋",
+            "café",
+            "日本語テキスト",
+        ] {
+            let mut s = original.to_string();
+            reverse_string_in_place(&mut s);
+            assert_eq!(s, reverse_string(original));
+        }
+    }
+
+    #[test]
+    fn test_reverse_string_in_place_double_reversal() {
+        let original = "Grüße, 世界! 🦀🎉";
+        let mut s = original.to_string();
+        reverse_string_in_place(&mut s);
+        reverse_string_in_place(&mut s);
+        assert_eq!(s, original);
+    }
+
+    #[test]
+    fn test_reverse_string_in_place_reuses_allocation() {
+        let mut s = String::with_capacity(64);
+        s.push_str("abc déf");
+        let ptr = s.as_ptr();
+        reverse_string_in_place(&mut s);
+        assert_eq!(s, "féd cba");
+        assert_eq!(s.as_ptr(), ptr);
+        assert_eq!(s.capacity(), 64);
+    }
+
+    // Tests for center
+    #[test]
+    fn test_center_even_padding() {
+        assert_eq!(center("hi", 6, '-'), "--hi--");
+        assert_eq!(center("", 4, '.'), "....");
+    }
+
+    #[test]
+    fn test_center_odd_padding() {
+        // The extra fill goes on the right
+        assert_eq!(center("hi", 5, '-'), "-hi--");
+        assert_eq!(center("abc", 4, ' '), "abc ");
+    }
+
+    #[test]
+    fn test_center_over_width() {
+        assert_eq!(center("hello", 3, '*'), "hello");
+        assert_eq!(center("hello", 5, '*'), "hello");
+    }
+
+    #[test]
+    fn test_center_unicode() {
+        // Width counts chars, not bytes
+        assert_eq!(center("é", 3, '·'), "·é·");
+    }
+
+    // Tests for expand_tabs
+    #[test]
+    fn test_expand_tabs_basic() {
+        assert_eq!(expand_tabs("a\tb", 4), "a   b");
+        assert_eq!(expand_tabs("no tabs", 4), "no tabs");
+    }
+
+    #[test]
+    fn test_expand_tabs_multiple_tabs() {
+        assert_eq!(expand_tabs("\t\tx", 4), "        x");
+        assert_eq!(expand_tabs("a\tbc\td", 4), "a   bc  d");
+    }
+
+    #[test]
+    fn test_expand_tabs_at_tab_stop() {
+        // A tab exactly at a stop advances a full width
+        assert_eq!(expand_tabs("abcd\te", 4), "abcd    e");
+        assert_eq!(expand_tabs("ab\tc", 2), "ab  c");
+    }
+
+    #[test]
+    fn test_expand_tabs_multi_line() {
+        assert_eq!(expand_tabs("abc\td\n\te", 4), "abc d\n    e");
+    }
+
+    #[test]
+    fn test_expand_tabs_zero_width() {
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+    }
+
+    // Tests for acronym
+    #[test]
+    fn test_acronym_basic() {
+        assert_eq!(acronym("portable document format"), "PDF");
+        assert_eq!(acronym("  as   soon as  possible "), "ASAP");
+    }
+
+    #[test]
+    fn test_acronym_mixed_case() {
+        assert_eq!(acronym("Hyper text Markup language"), "HTML");
+        assert_eq!(acronym("eXtensible Markup Language"), "EML");
+    }
+
+    #[test]
+    fn test_acronym_skips_non_letters() {
+        assert_eq!(acronym("1st place winner"), "PW");
+        assert_eq!(acronym("- bullet & point"), "BP");
+    }
+
+    #[test]
+    fn test_acronym_empty() {
+        assert_eq!(acronym(""), "");
+        assert_eq!(acronym("   "), "");
+        assert_eq!(acronym("42 99"), "");
+    }
+
+    // Tests for levenshtein_within
+    #[test]
+    fn test_levenshtein_within_agrees_under_threshold() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("flaw", "lawn"),
+            ("", "abc"),
+            ("abc", ""),
+            ("café", "cafe"),
+            ("intention", "execution"),
+            ("same", "same"),
+        ];
+        for (a, b) in pairs {
+            let d = levenshtein(a, b);
+            assert_eq!(levenshtein_within(a, b, d), Some(d), "{} / {}", a, b);
+            assert_eq!(levenshtein_within(a, b, d + 3), Some(d), "{} / {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_within_over_threshold() {
+        assert_eq!(levenshtein_within("kitten", "sitting", 2), None);
+        assert_eq!(levenshtein_within("intention", "execution", 4), None);
+        // Length difference alone exceeds the threshold
+        assert_eq!(levenshtein_within("a", "abcdef", 3), None);
+    }
+
+    #[test]
+    fn test_levenshtein_within_no_limit() {
+        let pairs = [
+            ("a", "b"),
+            ("", ""),
+            ("", "abc"),
+            ("kitten", "sitting"),
+            ("flaw", "lawn"),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(
+                levenshtein_within(a, b, usize::MAX),
+                Some(levenshtein(a, b))
+            );
+            assert_eq!(
+                levenshtein_within(a, b, usize::MAX - 1),
+                Some(levenshtein(a, b))
+            );
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_within_exhaustive_small() {
+        let words = [
+            "", "a", "ab", "ba", "abc", "acb", "bca", "aaaa", "abab", "xyz",
+        ];
+        for a in words {
+            for b in words {
+                let d = levenshtein(a, b);
+                for max in 0..6 {
+                    let expected = if d <= max { Some(d) } else { None };
+                    assert_eq!(
+                        levenshtein_within(a, b, max),
+                        expected,
+                        "{} / {} / {}",
+                        a,
+                        b,
+                        max
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_within_long_strings() {
+        let a = "a".repeat(5000);
+        let mut b = a.clone();
+        b.replace_range(2500..2501, "b");
+        assert_eq!(levenshtein_within(&a, &b, 2), Some(1));
+        assert_eq!(levenshtein_within(&a, &"b".repeat(5000), 10), None);
+    }
+
+    // Tests for longest_palindromic_substring
+    #[test]
+    fn test_longest_palindromic_substring_odd() {
+        assert_eq!(longest_palindromic_substring("babad"), "bab");
+        assert_eq!(longest_palindromic_substring("xracecary"), "racecar");
+    }
+
+    #[test]
+    fn test_longest_palindromic_substring_even() {
+        assert_eq!(longest_palindromic_substring("cbbd"), "bb");
+        assert_eq!(longest_palindromic_substring("abccbaz"), "abccba");
+    }
+
+    #[test]
+    fn test_longest_palindromic_substring_no_repeats() {
+        // Every single char is a palindrome; the first wins
+        assert_eq!(longest_palindromic_substring("abcde"), "a");
+    }
+
+    #[test]
+    fn test_longest_palindromic_substring_edge_cases() {
+        assert_eq!(longest_palindromic_substring(""), "");
+        assert_eq!(longest_palindromic_substring("z"), "z");
+        assert_eq!(longest_palindromic_substring("aaaa"), "aaaa");
+        // Chars, not bytes
+        assert_eq!(longest_palindromic_substring("xéaéy"), "éaé");
+    }
+
+    // Tests for edit_script
+    fn apply_script(a: &str, ops: &[EditOp]) -> String {
+        let mut source = a.chars();
+        let mut result = String::new();
+        for op in ops {
+            match *op {
+                EditOp::Keep(c) => {
+                    assert_eq!(source.next(), Some(c));
+                    result.push(c);
+                }
+                EditOp::Insert(c) => result.push(c),
+                EditOp::Delete(c) => assert_eq!(source.next(), Some(c)),
+            }
+        }
+        assert_eq!(source.next(), None);
+        result
+    }
+
+    #[test]
+    fn test_edit_script_insertion() {
+        let ops = edit_script("cat", "cart");
+        assert_eq!(
+            ops,
+            vec![
+                EditOp::Keep('c'),
+                EditOp::Keep('a'),
+                EditOp::Insert('r'),
+                EditOp::Keep('t'),
+            ]
+        );
+        assert_eq!(apply_script("cat", &ops), "cart");
+    }
+
+    #[test]
+    fn test_edit_script_deletion() {
+        let ops = edit_script("cart", "cat");
+        assert_eq!(
+            ops,
+            vec![
+                EditOp::Keep('c'),
+                EditOp::Keep('a'),
+                EditOp::Delete('r'),
+                EditOp::Keep('t'),
+            ]
+        );
+        assert_eq!(apply_script("cart", &ops), "cat");
+    }
+
+    #[test]
+    fn test_edit_script_substitution() {
+        let ops = edit_script("cat", "cut");
+        assert_eq!(
+            ops,
+            vec![
+                EditOp::Keep('c'),
+                EditOp::Delete('a'),
+                EditOp::Insert('u'),
+                EditOp::Keep('t'),
+            ]
+        );
+        assert_eq!(apply_script("cat", &ops), "cut");
+    }
+
+    #[test]
+    fn test_edit_script_round_trip() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("", "abc"),
+            ("abc", ""),
+            ("", ""),
+            ("intention", "execution"),
+            ("café", "cafe"),
+        ];
+        for (a, b) in pairs {
+            let ops = edit_script(a, b);
+            assert_eq!(apply_script(a, &ops), b);
+
+            // Substitutions count once, as in levenshtein
+            let inserts = ops
+                .iter()
+                .filter(|op| matches!(op, EditOp::Insert(_)))
+                .count();
+            let deletes = ops
+                .iter()
+                .filter(|op| matches!(op, EditOp::Delete(_)))
+                .count();
+            assert_eq!(inserts.max(deletes), levenshtein(a, b), "{} / {}", a, b);
+        }
+    }
+
+    // Tests for split_keep_delimiter
+    #[test]
+    fn test_split_keep_delimiter_basic() {
+        assert_eq!(
+            split_keep_delimiter("a,b,c", ','),
+            vec!["a", ",", "b", ",", "c"]
+        );
+        assert_eq!(split_keep_delimiter("abc", ','), vec!["abc"]);
+    }
+
+    #[test]
+    fn test_split_keep_delimiter_leading_and_trailing() {
+        assert_eq!(split_keep_delimiter(",a", ','), vec![",", "a"]);
+        assert_eq!(split_keep_delimiter("a,", ','), vec!["a", ","]);
+    }
+
+    #[test]
+    fn test_split_keep_delimiter_consecutive() {
+        assert_eq!(split_keep_delimiter("a,,b", ','), vec!["a", ",", ",", "b"]);
+        assert_eq!(split_keep_delimiter(",,", ','), vec![",", ","]);
+    }
+
+    #[test]
+    fn test_split_keep_delimiter_unicode_and_empty() {
+        assert_eq!(split_keep_delimiter("α→β", '→'), vec!["α", "→", "β"]);
+        assert!(split_keep_delimiter("", ',').is_empty());
+    }
+
+    // Tests for graphemes and nth_grapheme
+    #[test]
+    fn test_graphemes_zwj_emoji() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let s = format!("hi{}!", family);
+        assert_eq!(graphemes(&s), vec!["h", "i", family, "!"]);
+        assert_eq!(nth_grapheme(&s, 2), Some(family));
+    }
+
+    #[test]
+    fn test_graphemes_combining_marks() {
+        // "e" + combining acute, "n" + combining tilde
+        let s = "e\u{301}n\u{303}o";
+        assert_eq!(graphemes(s), vec!["e\u{301}", "n\u{303}", "o"]);
+        assert_eq!(graphemes(s).len(), display_width(s));
+        assert_eq!(nth_grapheme(s, 1), Some("n\u{303}"));
+    }
+
+    #[test]
+    fn test_nth_grapheme_out_of_range() {
+        assert_eq!(nth_grapheme("abc", 3), None);
+        assert_eq!(nth_grapheme("", 0), None);
+        assert!(graphemes("").is_empty());
+    }
+
+    // Tests for wrap_text_hyphenate
+    #[test]
+    fn test_wrap_text_hyphenate_long_word() {
+        let word = "abcdefghijklmnopqrstuvwxyz0123";
+        assert_eq!(word.len(), 30);
+        assert_eq!(
+            wrap_text_hyphenate(word, 10),
+            vec!["abcdefghi-", "jklmnopqr-", "stuvwxyz0-", "123"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_text_hyphenate_normal_words_unaffected() {
+        assert_eq!(
+            wrap_text_hyphenate("the quick brown fox jumps", 10),
+            vec!["the quick", "brown fox", "jumps"]
+        );
+        // A word exactly `width` long fits without a hyphen
+        assert_eq!(
+            wrap_text_hyphenate("abcdefghij k", 10),
+            vec!["abcdefghij", "k"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_text_hyphenate_tail_joins_next_word() {
+        assert_eq!(
+            wrap_text_hyphenate("go abcdefghijkl up", 6),
+            vec!["go", "abcde-", "fghij-", "kl up"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_text_hyphenate_edge_cases() {
+        assert!(wrap_text_hyphenate("", 10).is_empty());
+        assert!(wrap_text_hyphenate("   ", 10).is_empty());
+        assert_eq!(wrap_text_hyphenate("ab cd", 1), vec!["ab", "cd"]);
+        assert_eq!(wrap_text_hyphenate("abcd", 2), vec!["a-", "b-", "cd"]);
+        // Widths count chars, not bytes
+        assert_eq!(wrap_text_hyphenate("ééééé", 3), vec!["éé-", "ééé"]);
+    }
+
+    // Tests for is_balanced
+    #[test]
+    fn test_is_balanced_ignores_other_chars() {
+        assert!(is_balanced("a(b[c]d)e"));
+        assert!(is_balanced("no brackets at all"));
+        assert!(is_balanced(""));
+    }
+
+    #[test]
+    fn test_is_balanced_nested_mixed() {
+        assert!(is_balanced("{[()()]}"));
+        assert!(is_balanced("fn f() { let v = [(1, 2)]; }"));
+        assert!(!is_balanced("([)]"));
+        assert!(!is_balanced("{[}]"));
+    }
+
+    #[test]
+    fn test_is_balanced_unmatched() {
+        assert!(!is_balanced(")("));
+        assert!(!is_balanced("())"));
+        assert!(!is_balanced("]"));
+        assert!(!is_balanced("(("));
+        assert!(!is_balanced("{"));
+    }
 }
@@ -1,5 +1,11 @@
-use chrono::NaiveDate;
-use rust_utils_lib::{date_difference, format_date, parse_date, validate_date_format};
+use chrono::{Datelike, NaiveDate};
+use rust_utils_lib::{
+    business_days_between_excluding, date_difference, date_range_step, days_until_next_occurrence,
+    easter_sunday, format_date, from_unix_timestamp, full_years_between, humanize_days,
+    inclusive_days_between, month_starts, parse_any, parse_date, parse_date_ordered,
+    to_unix_timestamp, validate_and_parse, validate_date_detailed, validate_date_format,
+    week_of_month,
+};
 
 #[test]
 fn test_date_difference_integration() {
@@ -190,3 +196,277 @@ fn test_edge_case_dates() {
     let diff = date_difference(&jan1, &dec31);
     assert_eq!(diff.days, 365);
 }
+
+#[test]
+fn test_validate_and_parse_integration() {
+    // Agrees with validate_date_format and parse_date
+    let input = "25/12/2024";
+    assert!(validate_date_format(input, "DD/MM/YYYY"));
+    assert_eq!(
+        validate_and_parse(input, "DD/MM/YYYY"),
+        Some(parse_date(input).unwrap())
+    );
+
+    // Invalid input or format yields None
+    assert_eq!(validate_and_parse("31/02/2024", "DD/MM/YYYY"), None);
+    assert_eq!(validate_and_parse(input, "YYYY/DD/MM"), None);
+}
+
+#[test]
+fn test_inclusive_days_vacation() {
+    // A vacation from Monday to Friday lasts 5 days
+    let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 7, 5).unwrap();
+    assert_eq!(inclusive_days_between(&start, &end), 5);
+
+    // One more than the exclusive difference
+    assert_eq!(
+        inclusive_days_between(&start, &end),
+        date_difference(&start, &end).days + 1
+    );
+}
+
+#[test]
+fn test_parse_date_without_leading_zeros() {
+    // Day-first takes precedence when both orders are valid
+    assert_eq!(
+        parse_date("5/3/2024").unwrap(),
+        NaiveDate::from_ymd_opt(2024, 3, 5).unwrap()
+    );
+    assert_eq!(
+        parse_date("05/03/2024").unwrap(),
+        parse_date("5/3/2024").unwrap()
+    );
+
+    // Month-first is the fallback when day-first is impossible
+    assert_eq!(
+        parse_date("1/31/2024").unwrap(),
+        NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+    );
+}
+
+#[test]
+fn test_date_difference_describe_integration() {
+    let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+    let one_day = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    assert_eq!(date_difference(&start, &one_day).describe(), "1 day");
+
+    let three_weeks = NaiveDate::from_ymd_opt(2024, 3, 22).unwrap();
+    assert_eq!(date_difference(&start, &three_weeks).describe(), "3 weeks");
+
+    let later = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    assert_eq!(
+        date_difference(&start, &later).describe(),
+        "1 year, 1 week, 2 days"
+    );
+}
+
+#[test]
+fn test_full_years_vs_approximate_years() {
+    // Across many leap days the approximation drifts
+    let start = NaiveDate::from_ymd_opt(1900, 1, 10).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+    assert_eq!(full_years_between(&start, &end), 99);
+    assert_eq!(date_difference(&start, &end).years, 100);
+}
+
+#[test]
+fn test_date_range_step_integration() {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+    // Every Monday of 2024 (Jan 1 is a Monday)
+    let mondays: Vec<NaiveDate> = date_range_step(start, end, 7).collect();
+    assert_eq!(mondays.len(), 53);
+    assert!(mondays.iter().all(|d| d.weekday() == chrono::Weekday::Mon));
+
+    // A daily step visits every day of the leap year
+    assert_eq!(date_range_step(start, end, 1).count(), 366);
+}
+
+#[test]
+fn test_month_starts_billing_periods() {
+    let signup = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let periods = month_starts(signup, today);
+    assert_eq!(periods.len(), 12);
+    assert!(periods.iter().all(|d| d.day() == 1));
+    assert_eq!(periods.last(), Some(&today));
+}
+
+#[test]
+fn test_parse_date_ordered_locales() {
+    let input = "03/04/2024";
+
+    let european = parse_date_ordered(input, true).unwrap();
+    let american = parse_date_ordered(input, false).unwrap();
+    assert_ne!(european, american);
+    assert_eq!(european.month(), 4);
+    assert_eq!(american.month(), 3);
+
+    // Day-first matches the default parse_date behavior
+    assert_eq!(parse_date(input).unwrap(), european);
+}
+
+#[test]
+fn test_format_date_ordinal_prose() {
+    let date = parse_date("2024-03-21").unwrap();
+    let sentence = format!(
+        "The meeting is on {}.",
+        format_date(&date, "Month Do, YYYY").unwrap()
+    );
+    assert_eq!(sentence, "The meeting is on March 21st, 2024.");
+
+    let date = parse_date("2024-07-11").unwrap();
+    assert_eq!(
+        format_date(&date, "Month Do, YYYY"),
+        Some("July 11th, 2024".to_string())
+    );
+}
+
+#[test]
+fn test_week_of_month_follows_mondays() {
+    // Every Monday after the 1st starts a new week
+    let start = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+
+    let mut previous = week_of_month(&start);
+    for date in date_range_step(start, end, 1).skip(1) {
+        let week = week_of_month(&date);
+        if date.weekday() == chrono::Weekday::Mon {
+            assert_eq!(week, previous + 1);
+        } else {
+            assert_eq!(week, previous);
+        }
+        previous = week;
+    }
+    assert_eq!(previous, 5);
+}
+
+#[test]
+fn test_validate_date_detailed_form_messages() {
+    let inputs = ["25/12/2024", "12/25/2024", "25.12.2024"];
+    let messages: Vec<String> = inputs
+        .iter()
+        .map(|input| match validate_date_detailed(input, "DD/MM/YYYY") {
+            Ok(()) => "ok".to_string(),
+            Err(reason) => reason,
+        })
+        .collect();
+    assert_eq!(
+        messages,
+        ["ok", "invalid day/month", "does not match DD/MM/YYYY"]
+    );
+
+    // Agrees with the boolean validator
+    for input in inputs {
+        assert_eq!(
+            validate_date_detailed(input, "DD/MM/YYYY").is_ok(),
+            validate_date_format(input, "DD/MM/YYYY")
+        );
+    }
+}
+
+#[test]
+fn test_days_until_next_occurrence_reminders() {
+    let anniversary = parse_date("2015-06-20").unwrap();
+    let today = parse_date("2024-06-10").unwrap();
+
+    let days = days_until_next_occurrence(&anniversary, &today);
+    assert_eq!(days, 10);
+
+    // Landing on the occurrence counts as another full year
+    let on_the_day = today + chrono::TimeDelta::days(days);
+    assert_eq!(days_until_next_occurrence(&anniversary, &on_the_day), 0);
+    assert_eq!(
+        full_years_between(&anniversary, &on_the_day),
+        full_years_between(&anniversary, &today) + 1
+    );
+}
+
+#[test]
+fn test_parse_any_mixed_inputs() {
+    let inputs = ["2024-06-01", "2024-06-01 09:30:00", "2024-06-01T23:59:59"];
+    let parsed: Vec<_> = inputs.iter().map(|s| parse_any(s).unwrap()).collect();
+
+    // All on the same calendar day, in chronological order
+    assert!(
+        parsed
+            .iter()
+            .all(|dt| dt.date() == parse_date("2024-06-01").unwrap())
+    );
+    assert!(parsed.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_business_days_between_excluding_year() {
+    let start = parse_date("2024-01-01").unwrap();
+    let end = parse_date("2025-01-01").unwrap();
+
+    // 2024 has 262 weekdays
+    assert_eq!(business_days_between_excluding(&start, &end, &[]), 262);
+
+    // New Year's Day (Mon) and Christmas (Wed) are weekdays; July 4th is a Thursday
+    let holidays = [
+        parse_date("2024-01-01").unwrap(),
+        parse_date("2024-07-04").unwrap(),
+        parse_date("2024-12-25").unwrap(),
+        parse_date("2024-12-25").unwrap(),
+    ];
+    assert_eq!(
+        business_days_between_excluding(&start, &end, &holidays),
+        259
+    );
+}
+
+#[test]
+fn test_humanize_days_from_date_difference() {
+    let start = parse_date("2022-01-01").unwrap();
+    let end = parse_date("2024-04-15").unwrap();
+
+    let diff = date_difference(&start, &end);
+    assert_eq!(diff.days, 835);
+    assert_eq!(humanize_days(diff.days), "2 years, 3 months, 15 days");
+
+    // Reversing the dates only flips the sign
+    let reversed = date_difference(&end, &start);
+    assert_eq!(humanize_days(reversed.days), "-2 years, 3 months, 15 days");
+}
+
+#[test]
+fn test_easter_sunday_movable_holidays() {
+    let easter = easter_sunday(2024);
+
+    // Good Friday and Easter Monday
+    let good_friday = easter - chrono::TimeDelta::days(2);
+    let easter_monday = easter + chrono::TimeDelta::days(1);
+    assert_eq!(
+        format_date(&good_friday, "YYYY-MM-DD"),
+        Some("2024-03-29".to_string())
+    );
+    assert_eq!(easter_monday.weekday(), chrono::Weekday::Mon);
+
+    // Both fall on weekdays, so they reduce the working week
+    let start = parse_date("2024-03-25").unwrap();
+    let end = parse_date("2024-04-06").unwrap();
+    assert_eq!(
+        business_days_between_excluding(&start, &end, &[good_friday, easter_monday]),
+        8
+    );
+}
+
+#[test]
+fn test_unix_timestamp_integration() {
+    // Whole days since the epoch line up with date differences
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let date = NaiveDate::from_ymd_opt(2025, 6, 15).unwrap();
+    let secs = to_unix_timestamp(&date.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(secs, date_difference(&epoch, &date).days * 86_400);
+
+    let parsed = from_unix_timestamp(secs + 3_600).unwrap();
+    assert_eq!(parsed.date(), date);
+    assert_eq!(parsed.format("%H:%M:%S").to_string(), "01:00:00");
+}
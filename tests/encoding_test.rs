@@ -0,0 +1,25 @@
+#![cfg(feature = "base64")]
+
+use rust_utils_lib::{from_base64, to_base64};
+
+#[test]
+fn test_base64_round_trip_integration() {
+    let payloads: [&[u8]; 4] = [b"", b"f", b"fo", b"foo"];
+    let expected = ["", "Zg==", "Zm8=", "Zm9v"];
+
+    for (payload, encoded) in payloads.iter().zip(expected) {
+        assert_eq!(to_base64(payload), encoded);
+        assert_eq!(from_base64(encoded), Some(payload.to_vec()));
+    }
+}
+
+#[test]
+fn test_base64_text_payload() {
+    let text = "Hello, 世界!";
+    let encoded = to_base64(text.as_bytes());
+    let decoded = from_base64(&encoded).unwrap();
+    assert_eq!(String::from_utf8(decoded).unwrap(), text);
+
+    // Invalid characters are rejected
+    assert_eq!(from_base64("@@@@"), None);
+}
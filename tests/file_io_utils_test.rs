@@ -1,4 +1,8 @@
-use rust_utils_lib::{append_to_file, read_file, write_file};
+use rust_utils_lib::{
+    append_to_file, append_to_file_counted, diff_lines, grep_file, read_file, read_file_chunks,
+    read_file_no_bom, read_file_numbered, read_file_optional, read_file_or_default, read_last_line,
+    touch, write_file, write_file_if_changed, write_file_mkdir, write_file_with_mode,
+};
 use std::fs;
 
 // Helper function to clean up test files
@@ -200,3 +204,286 @@ fn test_create_file_in_current_directory() {
 
     cleanup_file(path);
 }
+
+#[test]
+fn test_append_counted_workflow() {
+    let path = "integration_test_append_counted.txt";
+
+    cleanup_file(path);
+
+    // Entries without a trailing newline get one added
+    let first = append_to_file_counted(path, "First entry", true).unwrap();
+    let second = append_to_file_counted(path, "Second entry\n", true).unwrap();
+
+    let result = read_file(path).unwrap();
+    assert_eq!(result, "First entry\nSecond entry\n");
+    assert_eq!(first + second, result.len());
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_optional_workflow() {
+    let path = "integration_test_read_optional.txt";
+
+    cleanup_file(path);
+
+    // Missing file is not an error
+    assert_eq!(read_file_optional(path).unwrap(), None);
+
+    // Once written, the contents are returned
+    write_file(path, "Now present").unwrap();
+    assert_eq!(
+        read_file_optional(path).unwrap(),
+        Some("Now present".to_string())
+    );
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_grep_log_file() {
+    let path = "integration_test_grep.txt";
+
+    write_file(path, "INFO start\n").unwrap();
+    append_to_file(path, "ERROR disk full\n").unwrap();
+    append_to_file(path, "INFO retry\n").unwrap();
+    append_to_file(path, "ERROR disk full again\n").unwrap();
+
+    let errors = grep_file(path, "ERROR").unwrap();
+    let line_numbers: Vec<usize> = errors.iter().map(|(n, _)| *n).collect();
+    assert_eq!(line_numbers, vec![2, 4]);
+    assert_eq!(errors[0].1, "ERROR disk full");
+
+    assert!(grep_file(path, "WARN").unwrap().is_empty());
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_write_file_with_mode_integration() {
+    let path = "integration_test_write_mode.txt";
+
+    cleanup_file(path);
+
+    write_file_with_mode(path, "#!/bin/sh\necho hi\n", 0o755).unwrap();
+    assert_eq!(read_file(path).unwrap(), "#!/bin/sh\necho hi\n");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path).unwrap().permissions().mode();
+        assert_ne!(mode & 0o100, 0, "owner execute bit should be set");
+    }
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_or_default_config_loading() {
+    let path = "integration_test_read_or_default.txt";
+
+    cleanup_file(path);
+
+    // No config yet: default is used
+    assert_eq!(read_file_or_default(path, "theme=light"), "theme=light");
+
+    // After saving a config, it is read back
+    write_file(path, "theme=dark").unwrap();
+    assert_eq!(read_file_or_default(path, "theme=light"), "theme=dark");
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_touch_then_append() {
+    let path = "integration_test_touch.txt";
+
+    cleanup_file(path);
+
+    // Touch creates an empty file
+    touch(path).unwrap();
+    assert_eq!(read_file(path).unwrap(), "");
+
+    // Touching again after writing keeps the data
+    append_to_file(path, "data\n").unwrap();
+    touch(path).unwrap();
+    assert_eq!(read_file(path).unwrap(), "data\n");
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_numbered_integration() {
+    let path = "integration_test_read_numbered.txt";
+
+    write_file(path, "fn main() {\n    println!(\"hi\");\n}\n").unwrap();
+
+    let listing = read_file_numbered(path).unwrap();
+    assert_eq!(
+        listing,
+        "1 | fn main() {\n2 |     println!(\"hi\");\n3 | }\n"
+    );
+
+    cleanup_file(path);
+}
+
+#[cfg(feature = "filelock")]
+#[test]
+fn test_append_locked_from_many_threads() {
+    use rust_utils_lib::append_to_file_locked;
+    use std::thread;
+
+    let path = "integration_test_append_locked.txt";
+
+    cleanup_file(path);
+
+    let handles: Vec<_> = (0..4)
+        .map(|worker| {
+            thread::spawn(move || {
+                for entry in 0..25 {
+                    let line = format!("worker {} entry {}\n", worker, entry);
+                    append_to_file_locked(path, &line).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // Every line is present exactly once and intact
+    let result = read_file(path).unwrap();
+    let mut lines: Vec<&str> = result.lines().collect();
+    lines.sort();
+    let mut expected: Vec<String> = (0..4)
+        .flat_map(|w| (0..25).map(move |e| format!("worker {} entry {}", w, e)))
+        .collect();
+    expected.sort();
+    assert_eq!(lines, expected);
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_file_directory_integration() {
+    let dir = "integration_test_read_dir";
+    fs::create_dir_all(dir).unwrap();
+
+    // A directory gets a clear error instead of an OS-specific one
+    let err = read_file(dir).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    // A missing path is still NotFound
+    let err = read_file("integration_test_read_dir_missing.txt").unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+
+    let _ = fs::remove_dir(dir);
+}
+
+#[test]
+fn test_write_file_mkdir_integration() {
+    let root = "integration_test_mkdir";
+    let _ = fs::remove_dir_all(root);
+    let path = format!("{}/reports/2024/summary.txt", root);
+
+    // Plain write_file fails without the parent directories
+    assert!(write_file(&path, "total: 3").is_err());
+
+    write_file_mkdir(&path, "total: 3").unwrap();
+    assert_eq!(read_file(&path).unwrap(), "total: 3");
+
+    // Writing again into the now-existing tree overwrites
+    write_file_mkdir(&path, "total: 4").unwrap();
+    assert_eq!(read_file(&path).unwrap(), "total: 4");
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn test_read_file_chunks_integration() {
+    let path = "integration_test_read_chunks.txt";
+    let content: String = (0..1000).map(|i| format!("line {}\n", i)).collect();
+    write_file(path, &content).unwrap();
+
+    // Chunks reassemble to the same bytes as reading the whole file
+    let mut bytes = Vec::new();
+    read_file_chunks(path, 4096, |chunk| bytes.extend_from_slice(chunk)).unwrap();
+    assert_eq!(bytes, read_file(path).unwrap().into_bytes());
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_file_no_bom_integration() {
+    let path = "integration_test_read_no_bom.csv";
+    fs::write(path, "\u{FEFF}name,score\nada,10\n").unwrap();
+
+    // The header parses cleanly once the BOM is gone
+    let contents = read_file_no_bom(path).unwrap();
+    let header: Vec<&str> = contents.lines().next().unwrap().split(',').collect();
+    assert_eq!(header, ["name", "score"]);
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_diff_lines_integration() {
+    let (old, new) = (
+        "integration_test_diff_old.txt",
+        "integration_test_diff_new.txt",
+    );
+    write_file(old, "name = demo\nversion = 1\n").unwrap();
+    fs::copy(old, new).unwrap();
+
+    // Unchanged copy has no differences
+    assert!(diff_lines(old, new).unwrap().is_empty());
+
+    // Bump the version and append a line
+    write_file(new, "name = demo\nversion = 2\nlicense = MIT\n").unwrap();
+    let diff = diff_lines(old, new).unwrap();
+    let changed: Vec<usize> = diff.iter().map(|(line, _, _)| *line).collect();
+    assert_eq!(changed, vec![2, 3]);
+    assert_eq!(diff[1].1, None);
+
+    cleanup_file(old);
+    cleanup_file(new);
+}
+
+#[test]
+fn test_write_file_if_changed_integration() {
+    let path = "integration_test_write_if_changed.txt";
+    cleanup_file(path);
+
+    let writes: Vec<bool> = ["v1", "v1", "v2", "v2", "v1"]
+        .iter()
+        .map(|content| write_file_if_changed(path, content).unwrap())
+        .collect();
+    assert_eq!(writes, vec![true, false, true, false, true]);
+    assert_eq!(read_file(path).unwrap(), "v1");
+
+    cleanup_file(path);
+}
+
+#[test]
+fn test_read_last_line_log_monitoring() {
+    let path = "integration_test_read_last_line.log";
+    cleanup_file(path);
+
+    for i in 1..=500 {
+        append_to_file(path, &format!("entry {}\n", i)).unwrap();
+        if i % 100 == 0 {
+            // The newest entry is always the last line
+            assert_eq!(read_last_line(path).unwrap(), Some(format!("entry {}", i)));
+        }
+    }
+
+    // Agrees with reading the whole file
+    let contents = read_file(path).unwrap();
+    assert_eq!(
+        read_last_line(path).unwrap().as_deref(),
+        contents.lines().last()
+    );
+
+    cleanup_file(path);
+}
@@ -1,4 +1,11 @@
-use rust_utils_lib::{factorial, gcd, is_prime};
+use rust_utils_lib::{
+    DivisorClass, GcdAccumulator, PrimeIter, ackermann, are_coprime, classify_number,
+    compare_fractions, continued_fraction, count_set_bits, crt, digit_sum_base, factorial,
+    factorial_ratio, fibonacci_up_to, gamma, gcd, gcd_iter, goldbach_pair, is_even,
+    is_numeric_palindrome, is_odd, is_pandigital, is_prime, is_prime_generic, is_triangular,
+    mod_add, mod_inverse, mod_mul, prime_pi, primorial, solve_quadratic, sqrt_newton, sum_of_cubes,
+    sum_of_squares, sum_primes_below, triangular,
+};
 
 #[test]
 fn test_factorial_integration() {
@@ -108,3 +115,371 @@ fn test_prime_density() {
     let prime_count = (2..=100).filter(|&n| is_prime(n)).count();
     assert_eq!(prime_count, 25);
 }
+
+#[test]
+fn test_prime_iter_integration() {
+    // The 100th prime is 541
+    assert_eq!(PrimeIter::new().nth(99), Some(541));
+
+    // Every yielded value is prime
+    assert!(PrimeIter::new().take(50).all(is_prime));
+
+    // Sum of primes below 100
+    let sum: u64 = PrimeIter::new().take_while(|&p| p < 100).sum();
+    assert_eq!(sum, 1060);
+}
+
+#[cfg(feature = "bignum")]
+#[test]
+fn test_factorial_bignum_integration() {
+    use rust_utils_lib::factorial_bignum;
+
+    // Agrees with the u64 version where it fits
+    assert_eq!(
+        factorial_bignum(20).to_string(),
+        factorial(20).unwrap().to_string()
+    );
+
+    // Keeps going where the u64 version overflows
+    assert_eq!(factorial(21), None);
+    assert_eq!(factorial_bignum(21).to_string(), "51090942171709440000");
+}
+
+#[test]
+fn test_digit_sum_base_integration() {
+    // Hex, decimal, and binary views of the same number
+    assert_eq!(digit_sum_base(255, 16), Some(30));
+    assert_eq!(digit_sum_base(255, 10), Some(12));
+    assert_eq!(digit_sum_base(255, 2), Some(8));
+
+    // Out-of-range bases are rejected
+    assert_eq!(digit_sum_base(255, 1), None);
+    assert_eq!(digit_sum_base(255, 37), None);
+}
+
+#[test]
+fn test_is_prime_generic_integration() {
+    // Works directly on common index and length types
+    let values: Vec<usize> = vec![1, 2, 3, 4, 5];
+    let prime_count = values.iter().filter(|&&v| is_prime_generic(v)).count();
+    assert_eq!(prime_count, 3);
+
+    // Same answers as the u64 version
+    assert_eq!(is_prime_generic(7919u32), is_prime(7919));
+    assert_eq!(is_prime_generic(7917u16), is_prime(7917));
+}
+
+#[test]
+fn test_gcd_iter_integration() {
+    let values = [120, 84, 36, 1000];
+
+    // Same as folding gcd by hand
+    let manual = values.iter().fold(0, |acc, &v| gcd(acc, v));
+    assert_eq!(gcd_iter(values.iter().copied()), manual);
+
+    // Works with adapters like filter
+    let multiples_of_three = gcd_iter(values.iter().copied().filter(|v| v % 3 == 0));
+    assert_eq!(multiples_of_three, 12);
+}
+
+#[test]
+fn test_are_coprime_integration() {
+    // Consecutive integers are always coprime
+    for n in 1..50 {
+        assert!(are_coprime(n, n + 1));
+    }
+
+    // Distinct primes are coprime
+    assert!(are_coprime(13, 29));
+
+    // Agrees with gcd
+    assert_eq!(are_coprime(48, 18), gcd(48, 18) == 1);
+}
+
+#[test]
+fn test_factorial_ratio_permutations() {
+    // Number of ways to pick an ordered podium of 3 from 30 runners: 30! / 27!
+    assert_eq!(factorial_ratio(30, 27), Some(30 * 29 * 28));
+
+    // Matches the plain factorial division when both fit
+    assert_eq!(
+        factorial_ratio(12, 7),
+        Some(factorial(12).unwrap() / factorial(7).unwrap())
+    );
+}
+
+#[test]
+fn test_sum_primes_below_integration() {
+    // Agrees with summing the prime iterator
+    let expected: u64 = PrimeIter::new().take_while(|&p| p < 1000).sum();
+    assert_eq!(sum_primes_below(1000), expected);
+    assert_eq!(sum_primes_below(1000), 76_127);
+}
+
+#[test]
+fn test_goldbach_pair_integration() {
+    assert_eq!(goldbach_pair(4), Some((2, 2)));
+    assert_eq!(goldbach_pair(100), Some((3, 97)));
+
+    let (p, q) = goldbach_pair(1_000_000).unwrap();
+    assert!(is_prime(p) && is_prime(q));
+    assert_eq!(p + q, 1_000_000);
+
+    assert_eq!(goldbach_pair(101), None);
+}
+
+#[test]
+fn test_gamma_integration() {
+    // Γ(n + 1) = n! for integers
+    assert!((gamma(11.0) - factorial(10).unwrap() as f64).abs() < 1e-6);
+
+    // Γ(x + 1) = x Γ(x) for non-integers too
+    let x = 3.7;
+    assert!((gamma(x + 1.0) - x * gamma(x)).abs() < 1e-9);
+}
+
+#[test]
+fn test_power_sums_integration() {
+    // Nicomachus's theorem: the sum of cubes is the square of the sum
+    for n in [1, 5, 50, 1000] {
+        let sum = n * (n + 1) / 2;
+        assert_eq!(sum_of_cubes(n), Some(sum * sum));
+    }
+
+    // Sum of squares grows more slowly, so it stays in range longer
+    assert!(sum_of_squares(1_000_000).is_some());
+    assert_eq!(sum_of_cubes(1_000_000), None);
+}
+
+#[test]
+fn test_triangular_integration() {
+    // Every triangular number is recognized, and nothing in between is
+    for n in 1..1000 {
+        let t = triangular(n).unwrap();
+        assert!(is_triangular(t));
+        assert!(!is_triangular(t + 1));
+    }
+
+    // The sum of cubes is the square of a triangular number
+    let t = triangular(20).unwrap();
+    assert_eq!(sum_of_cubes(20), Some(t * t));
+}
+
+#[test]
+fn test_classify_number_integration() {
+    // 12 is the smallest abundant number
+    let first_abundant = (1..).find(|&n| classify_number(n) == DivisorClass::Abundant);
+    assert_eq!(first_abundant, Some(12));
+
+    // Every prime is deficient
+    assert!(
+        PrimeIter::new()
+            .take(100)
+            .all(|p| classify_number(p) == DivisorClass::Deficient)
+    );
+}
+
+#[test]
+fn test_mod_inverse_integration() {
+    // Every non-zero residue has an inverse modulo a prime
+    let p = 101;
+    for a in 1..p {
+        let inv = mod_inverse(a, p).unwrap();
+        assert_eq!(a * inv % p, 1);
+    }
+
+    // An inverse exists exactly when the numbers are coprime
+    for a in 0..30 {
+        assert_eq!(mod_inverse(a, 30).is_some(), are_coprime(a, 30));
+    }
+}
+
+#[test]
+fn test_crt_integration() {
+    let residues = [1, 4, 6, 0];
+    let moduli = [5, 7, 11, 13];
+
+    let (x, m) = crt(&residues, &moduli).unwrap();
+    assert_eq!(m, 5 * 7 * 11 * 13);
+    assert!((x as u64) < m);
+
+    // The solution satisfies every congruence
+    for (&r, &n) in residues.iter().zip(&moduli) {
+        assert_eq!(x % n as i64, r);
+    }
+}
+
+#[test]
+fn test_prime_pi_integration() {
+    // Agrees with counting is_prime and the prime iterator
+    for n in [0, 1, 2, 50, 541, 2000] {
+        let by_trial = (0..=n).filter(|&k| is_prime(k)).count() as u64;
+        let by_iter = PrimeIter::new().take_while(|&p| p <= n).count() as u64;
+        assert_eq!(prime_pi(n), by_trial);
+        assert_eq!(prime_pi(n), by_iter);
+    }
+}
+
+#[test]
+fn test_compare_fractions_sorting() {
+    let mut fractions = vec![(1, 2), (-1, 3), (2, -3), (5, 10), (3, 4)];
+    fractions.sort_by(|&a, &b| compare_fractions(a, b).unwrap());
+
+    // Equal fractions keep their relative order (stable sort)
+    assert_eq!(fractions, vec![(2, -3), (-1, 3), (1, 2), (5, 10), (3, 4)]);
+}
+
+#[test]
+fn test_modular_arithmetic_chain() {
+    let m = 1_000_000_007;
+
+    // Fermat's little theorem: a^(p-1) ≡ 1 (mod p) by repeated squaring
+    let (mut base, mut exp, mut result) = (123_456_789u64, m - 1, 1u64);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    assert_eq!(result, 1);
+
+    // a * a⁻¹ ≡ 1 and (a + (m - a)) ≡ 0
+    let a = 987_654_321;
+    assert_eq!(mod_mul(a, mod_inverse(a, m).unwrap(), m), 1);
+    assert_eq!(mod_add(a, m - a, m), 0);
+}
+
+#[test]
+fn test_is_pandigital_integration() {
+    // There are 3! = 6 three-digit pandigitals
+    let count = (100..1000).filter(|&n| is_pandigital(n)).count();
+    assert_eq!(count, factorial(3).unwrap() as usize);
+
+    // The largest pandigital prime has 7 digits
+    assert!(is_pandigital(7_652_413) && is_prime(7_652_413));
+}
+
+#[test]
+fn test_is_numeric_palindrome_integration() {
+    // Agrees with the string-based palindrome check on digits
+    for n in (0..20_000).step_by(7) {
+        let digits = n.to_string();
+        let reversed: String = digits.chars().rev().collect();
+        assert_eq!(is_numeric_palindrome(n), digits == reversed);
+    }
+}
+
+#[test]
+fn test_bit_helpers_integration() {
+    // Powers of two have exactly one set bit
+    for shift in 0..64 {
+        assert_eq!(count_set_bits(1 << shift), 1);
+    }
+
+    // Every prime except 2 is odd
+    assert!(PrimeIter::new().skip(1).take(100).all(is_odd));
+    assert!(is_even(2) && is_prime(2));
+}
+
+#[test]
+fn test_continued_fraction_round_trip() {
+    // Folding the quotients back up recovers the reduced fraction
+    for (num, den) in [(415u64, 93u64), (3, 8), (1_000_000, 7), (144, 89)] {
+        let cf = continued_fraction(num, den);
+        let (mut p, mut q) = (1u64, 0u64);
+        for &a in cf.iter().rev() {
+            (p, q) = (a * p + q, p);
+        }
+        let g = gcd(num, den);
+        assert_eq!((p, q), (num / g, den / g));
+    }
+}
+
+#[test]
+fn test_fibonacci_up_to_integration() {
+    let fibs = fibonacci_up_to(10_000);
+
+    // Consecutive Fibonacci numbers are coprime
+    assert!(fibs[1..].windows(2).all(|w| are_coprime(w[0], w[1])));
+
+    // Sum of the first n is F(n + 2) - 1
+    let sum: u64 = fibs[..10].iter().sum();
+    assert_eq!(sum, fibs[11] - 1);
+}
+
+#[test]
+fn test_sqrt_newton_integration() {
+    // Perfect squares come out (almost) exact
+    for n in 1..100u64 {
+        let root = sqrt_newton((n * n) as f64, 1e-12).unwrap();
+        assert!((root - n as f64).abs() < 1e-9);
+    }
+
+    // Agrees with the exact integer test for triangular numbers
+    let x = triangular(1000).unwrap();
+    let root = sqrt_newton((8 * x + 1) as f64, 1e-12).unwrap();
+    assert_eq!(root.fract(), 0.0);
+    assert!(is_triangular(x));
+}
+
+#[test]
+fn test_solve_quadratic_integration() {
+    // Roots plugged back in satisfy the equation
+    let (a, b, c) = (3.0, -7.5, 1.25);
+    let roots = solve_quadratic(a, b, c);
+    assert_eq!(roots.len(), 2);
+    assert!(roots[0] < roots[1]);
+    for x in roots {
+        assert!((a * x * x + b * x + c).abs() < 1e-12);
+    }
+
+    // Vieta: (x - 2)(x - 5) = x² - 7x + 10
+    assert_eq!(solve_quadratic(1.0, -7.0, 10.0), vec![2.0, 5.0]);
+}
+
+#[test]
+fn test_gcd_accumulator_streaming() {
+    let stream = [360u64, 840, 1260, 2100];
+
+    let mut acc = GcdAccumulator::new();
+    for (seen, &x) in stream.iter().enumerate() {
+        acc.push(x);
+        // Always matches the batch computation over the prefix
+        assert_eq!(acc.value(), gcd_iter(stream[..=seen].iter().copied()));
+    }
+    assert_eq!(acc.value(), 60);
+}
+
+#[test]
+fn test_ackermann_integration() {
+    // Row m = 1 is n + 2, row m = 2 is 2n + 3
+    for n in 0..10 {
+        assert_eq!(ackermann(1, n), Some(n + 2));
+        assert_eq!(ackermann(2, n), Some(2 * n + 3));
+    }
+
+    // Each row m = 3 value is the previous row applied to its predecessor
+    for n in 1..10 {
+        let previous = ackermann(3, n - 1).unwrap();
+        assert_eq!(ackermann(3, n), ackermann(2, previous));
+    }
+
+    assert_eq!(ackermann(4, 2), None);
+}
+
+#[test]
+fn test_primorial_integration() {
+    // Each primorial is the previous one times the next prime
+    for (n, p) in (1..=15).zip(PrimeIter::new()) {
+        assert_eq!(primorial(n), primorial(n - 1).map(|prev| prev * p));
+    }
+
+    // Every primorial past the first is even but, being square-free, never
+    // divisible by 4
+    for n in 1..=15 {
+        let value = primorial(n).unwrap();
+        assert!(is_even(value));
+        assert_ne!(value % 4, 0);
+    }
+}
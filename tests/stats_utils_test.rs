@@ -0,0 +1,84 @@
+use rust_utils_lib::{
+    mean_absolute_deviation, min_max_scale, mode_f64, percentile, weighted_mean, z_scores,
+};
+
+#[test]
+fn test_mode_f64_integration() {
+    // Noisy sensor readings clustering around 20.0
+    let readings = [19.98, 20.01, 25.3, 20.02, 14.7, 19.99];
+    let mode = mode_f64(&readings, 0.05).unwrap();
+    assert!((mode - 20.0).abs() < 0.01);
+
+    // A zero tolerance only groups exact matches
+    assert_eq!(mode_f64(&[1.5, 1.5, 1.6], 0.0), Some(1.5));
+
+    // Empty input has no mode
+    assert_eq!(mode_f64(&[], 0.1), None);
+}
+
+#[test]
+fn test_percentile_latency_report() {
+    let latencies: Vec<f64> = (1..=100).map(|ms| ms as f64).collect();
+
+    let p50 = percentile(&latencies, 50.0).unwrap();
+    let p95 = percentile(&latencies, 95.0).unwrap();
+    let p99 = percentile(&latencies, 99.0).unwrap();
+
+    assert_eq!(p50, 50.5);
+    assert!((p95 - 95.05).abs() < 1e-9);
+    assert!((p99 - 99.01).abs() < 1e-9);
+    assert!(p50 < p95 && p95 < p99);
+}
+
+#[test]
+fn test_weighted_mean_course_grade() {
+    // Homework 20%, midterm 30%, final 50%
+    let scores = [90.0, 70.0, 80.0];
+    let weights = [0.2, 0.3, 0.5];
+    let grade = weighted_mean(&scores, &weights).unwrap();
+    assert!((grade - 79.0).abs() < 1e-9);
+
+    // Invalid inputs
+    assert_eq!(weighted_mean(&scores, &weights[..2]), None);
+    assert_eq!(weighted_mean(&scores, &[0.0, 0.0, 0.0]), None);
+}
+
+#[test]
+fn test_mad_is_robust_to_outliers() {
+    let clean = [10.0, 11.0, 9.0, 10.0, 10.0];
+    let with_outlier = [10.0, 11.0, 9.0, 10.0, 100.0];
+
+    let mad_clean = mean_absolute_deviation(&clean).unwrap();
+    let mad_outlier = mean_absolute_deviation(&with_outlier).unwrap();
+
+    assert!((mad_clean - 0.4).abs() < 1e-9);
+    assert!(mad_outlier > mad_clean);
+}
+
+#[test]
+fn test_min_max_scale_integration() {
+    let temperatures = [-5.0, 0.0, 15.0, 35.0];
+    let scaled = min_max_scale(&temperatures).unwrap();
+
+    assert_eq!(scaled.len(), temperatures.len());
+    assert_eq!(scaled[0], 0.0);
+    assert_eq!(scaled[3], 1.0);
+    assert!(scaled.iter().all(|&v| (0.0..=1.0).contains(&v)));
+    assert_eq!(scaled[2], 0.5);
+}
+
+#[test]
+fn test_z_scores_integration() {
+    let heights = [160.0, 170.0, 180.0];
+    let scores = z_scores(&heights).unwrap();
+
+    // Symmetric data: the middle value is at the mean
+    assert_eq!(scores[1], 0.0);
+    assert!((scores[0] + scores[2]).abs() < 1e-12);
+
+    // Mean ~0 and std dev ~1
+    let mean: f64 = scores.iter().sum::<f64>() / 3.0;
+    let variance: f64 = scores.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / 3.0;
+    assert!(mean.abs() < 1e-12);
+    assert!((variance.sqrt() - 1.0).abs() < 1e-12);
+}
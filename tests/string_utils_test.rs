@@ -1,4 +1,12 @@
-use rust_utils_lib::{count_char, is_palindrome, reverse_string};
+use rust_utils_lib::{
+    EditOp, acronym, center, count_byte, count_char, count_char_casefold, count_chars,
+    count_words_matching, damerau_levenshtein, display_width, edit_script, expand_tabs, from_hex,
+    graphemes, is_balanced, is_palindrome, is_palindrome_with, join_with_capacity, levenshtein,
+    levenshtein_ratio, levenshtein_within, line_count, longest_palindromic_substring,
+    most_common_chars, normalize_newlines, nth_grapheme, reverse_preserving_marks, reverse_string,
+    reverse_string_in_place, split_keep_delimiter, strip_ansi_codes, to_hex, to_pig_latin,
+    tokenize_with_positions, top_words, trim_report, word_frequency, wrap_text_hyphenate,
+};
 
 #[test]
 fn test_is_palindrome_integration() {
@@ -64,8 +72,8 @@ fn test_combined_string_operations() {
     // Count characters in a reversed string
     let original = "hello";
     let reversed = reverse_string(original);
-    assert_eq!(count_char(&original, 'l'), count_char(&reversed, 'l'));
-    assert_eq!(count_char(&original, 'h'), count_char(&reversed, 'h'));
+    assert_eq!(count_char(original, 'l'), count_char(&reversed, 'l'));
+    assert_eq!(count_char(original, 'h'), count_char(&reversed, 'h'));
 }
 
 #[test]
@@ -106,3 +114,428 @@ fn test_double_reverse_is_identity() {
         assert_eq!(s, reversed_twice);
     }
 }
+
+#[test]
+fn test_reverse_preserving_marks_integration() {
+    // Accents stay attached to their base letter
+    assert_eq!(reverse_preserving_marks("e\u{0301}abc"), "cbae\u{0301}");
+    assert_eq!(reverse_preserving_marks("cafe\u{0301}"), "e\u{0301}fac");
+
+    // Strings without marks behave like reverse_string
+    assert_eq!(reverse_preserving_marks("Rust"), reverse_string("Rust"));
+
+    // Double reversal is the identity
+    let s = "n\u{0303}o\u{0308}";
+    assert_eq!(reverse_preserving_marks(&reverse_preserving_marks(s)), s);
+}
+
+#[test]
+fn test_levenshtein_integration() {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("sitting", "kitten"), 3); // Symmetric
+    assert_eq!(levenshtein("", "rust"), 4);
+}
+
+#[test]
+fn test_levenshtein_ratio_ranking() {
+    // Closer matches rank higher
+    let query = "rust";
+    let mut candidates = ["dust", "rust", "trust", "java"];
+    candidates.sort_by(|a, b| {
+        levenshtein_ratio(query, b)
+            .partial_cmp(&levenshtein_ratio(query, a))
+            .unwrap()
+    });
+    assert_eq!(candidates[0], "rust");
+    assert_eq!(candidates[3], "java");
+}
+
+#[test]
+fn test_damerau_levenshtein_typos() {
+    // Common transposition typos cost a single edit
+    assert_eq!(damerau_levenshtein("recieve", "receive"), 1);
+    assert_eq!(levenshtein("recieve", "receive"), 2);
+
+    // Insertions and deletions match plain Levenshtein
+    assert_eq!(
+        damerau_levenshtein("color", "colour"),
+        levenshtein("color", "colour")
+    );
+}
+
+#[test]
+fn test_count_words_matching_integration() {
+    let text = "NASA and ESA launched the Artemis mission";
+
+    // Words longer than 3 chars
+    assert_eq!(count_words_matching(text, |w| w.len() > 3), 4);
+
+    // All-uppercase acronyms
+    assert_eq!(
+        count_words_matching(text, |w| w.chars().all(|c| c.is_uppercase())),
+        2
+    );
+}
+
+#[test]
+fn test_count_byte_large_input() {
+    // Several megabytes of ASCII log lines
+    let line = "2024-01-01 INFO request handled in 12ms\n";
+    let log = line.repeat(100_000);
+
+    assert_eq!(count_byte(&log, b'\n'), 100_000);
+    assert_eq!(count_byte(&log, b'I'), count_char(&log, 'I'));
+}
+
+#[test]
+fn test_display_width_vs_len_and_chars() {
+    // Precomposed and decomposed forms look the same but differ in chars and bytes
+    let precomposed = "café";
+    let decomposed = "cafe\u{0301}";
+    assert_eq!(display_width(precomposed), display_width(decomposed));
+    assert_ne!(precomposed.chars().count(), decomposed.chars().count());
+
+    // Flags are two regional indicators rendered as one symbol
+    let flag = "🇯🇵";
+    assert_eq!(display_width(flag), 1);
+    assert_eq!(flag.chars().count(), 2);
+    assert_eq!(flag.len(), 8);
+}
+
+#[test]
+fn test_pig_latin_sentence() {
+    assert_eq!(
+        to_pig_latin("the quick brown fox jumps over the lazy dog"),
+        "ethay uickqay ownbray oxfay umpsjay overway ethay azylay ogday"
+    );
+
+    // Word count is preserved
+    let sentence = "pig latin is fun";
+    assert_eq!(
+        to_pig_latin(sentence).split_whitespace().count(),
+        sentence.split_whitespace().count()
+    );
+}
+
+#[test]
+fn test_most_common_chars_integration() {
+    let text = "Mississippi";
+    assert_eq!(most_common_chars(text, 2), vec![('i', 4), ('s', 4)]);
+
+    // Counts agree with count_char
+    for (c, count) in most_common_chars(text, 10) {
+        assert_eq!(count, count_char(text, c));
+    }
+}
+
+#[test]
+fn test_is_palindrome_with_integration() {
+    let text = "Step on no pets!";
+
+    // Default behavior ignores spaces and punctuation
+    assert!(is_palindrome(text));
+    assert!(is_palindrome_with(text, |c| c.is_alphanumeric()));
+
+    // Counting spaces still works since they mirror each other
+    assert!(is_palindrome_with(text, |c| c != '!'));
+
+    // Counting the exclamation mark breaks the palindrome
+    assert!(!is_palindrome_with(text, |_| true));
+}
+
+#[test]
+fn test_trim_report_fixed_width_field() {
+    // A right-aligned field of width 8
+    let field = "   42.50";
+    let (leading, value, trailing) = trim_report(field);
+    assert_eq!(value, "42.50");
+    assert_eq!(leading + value.chars().count() + trailing, 8);
+    assert_eq!(trailing, 0);
+}
+
+#[test]
+fn test_keyword_extraction() {
+    let text = "Rust is fast. Rust is safe. Rust is fun!";
+
+    let freq = word_frequency(text);
+    assert_eq!(freq["rust"], 3);
+    assert_eq!(freq["is"], 3);
+    assert_eq!(freq["fast"], 1);
+
+    assert_eq!(
+        top_words(text, 2),
+        vec![("is".to_string(), 3), ("rust".to_string(), 3)]
+    );
+}
+
+#[test]
+fn test_strip_ansi_captured_output() {
+    let captured = "\x1b[32m✔\x1b[0m tests passed\n\x1b[1;31m✘\x1b[0m 1 failed\n";
+    let clean = strip_ansi_codes(captured);
+    assert_eq!(clean, "✔ tests passed\n✘ 1 failed\n");
+
+    // Stripping is idempotent
+    assert_eq!(strip_ansi_codes(&clean), clean);
+}
+
+#[test]
+fn test_normalize_newlines_round_trip() {
+    let unix = "line 1\nline 2\nline 3\n";
+    let windows = normalize_newlines(unix, "crlf");
+    assert_eq!(windows, "line 1\r\nline 2\r\nline 3\r\n");
+    assert_eq!(normalize_newlines(&windows, "lf"), unix);
+}
+
+#[test]
+fn test_hex_encoding_integration() {
+    let message = "Rust 🦀";
+    let encoded = to_hex(message);
+    assert_eq!(encoded, "5275737420f09fa680");
+    assert_eq!(from_hex(&encoded), Some(message.to_string()));
+
+    // Uppercase input decodes the same
+    assert_eq!(from_hex(&encoded.to_uppercase()), Some(message.to_string()));
+
+    // Malformed input
+    assert_eq!(from_hex("zz"), None);
+    assert_eq!(from_hex("abc"), None);
+}
+
+#[test]
+fn test_line_count_integration() {
+    let text = "first\nsecond\nthird\n";
+    assert_eq!(line_count(text), 3);
+
+    // Agrees with counting newlines when the text ends with one
+    assert_eq!(line_count(text), count_char(text, '\n'));
+
+    // Line endings don't change the count
+    assert_eq!(line_count(&normalize_newlines(text, "crlf")), 3);
+}
+
+#[test]
+fn test_tokenize_with_positions_highlighting() {
+    let text = "the  quick brown fox";
+
+    // Offsets slice back to the same words
+    for (offset, word) in tokenize_with_positions(text) {
+        assert_eq!(&text[offset..offset + word.len()], word);
+    }
+
+    // Locate a matched token for highlighting
+    let hit = tokenize_with_positions(text)
+        .into_iter()
+        .find(|&(_, w)| w == "brown");
+    assert_eq!(hit, Some((11, "brown")));
+}
+
+#[test]
+fn test_count_char_casefold_integration() {
+    let text = "Das Maß der Dinge. MASS!";
+
+    // count_char is case-sensitive; the casefold version sees every variant
+    assert_eq!(count_char(text, 'ß'), 1);
+    assert_eq!(count_char_casefold(text, 'ß'), 2);
+
+    // Plain ASCII agrees with lowercasing both sides
+    let expected = count_char(&text.to_lowercase(), 'd');
+    assert_eq!(count_char_casefold(text, 'D'), expected);
+}
+
+#[test]
+fn test_join_with_capacity_integration() {
+    let words: Vec<&str> = "the quick brown fox".split(' ').collect();
+    let joined = join_with_capacity(&words, "-");
+    assert_eq!(joined, "the-quick-brown-fox");
+    assert_eq!(joined.capacity(), joined.len());
+
+    // Round-trips through tokenizing
+    let tokens: Vec<&str> = tokenize_with_positions("the quick brown fox")
+        .into_iter()
+        .map(|(_, w)| w)
+        .collect();
+    assert_eq!(join_with_capacity(&tokens, "-"), joined);
+}
+
+#[test]
+fn test_count_chars_integration() {
+    let text = "Mississippi river";
+    let targets = ['s', 'i', 'p', 'x'];
+
+    // One pass gives the same answer as counting each target separately
+    let counts = count_chars(text, &targets);
+    for target in targets {
+        assert_eq!(counts[&target], count_char(text, target));
+    }
+}
+
+#[test]
+fn test_reverse_string_in_place_integration() {
+    let mut buffer = String::from("A man a plan 🌍");
+    let expected = reverse_string(&buffer);
+
+    reverse_string_in_place(&mut buffer);
+    assert_eq!(buffer, expected);
+
+    // Palindromes are unchanged
+    let mut palindrome = String::from("racecar");
+    reverse_string_in_place(&mut palindrome);
+    assert!(is_palindrome(&palindrome));
+    assert_eq!(palindrome, "racecar");
+}
+
+#[test]
+fn test_center_dashboard() {
+    let rows = ["CPU", "Memory", "Disk"];
+    let lines: Vec<String> = rows.iter().map(|r| center(r, 10, ' ')).collect();
+
+    assert!(lines.iter().all(|l| l.chars().count() == 10));
+    assert_eq!(lines[0], "   CPU    ");
+    assert_eq!(lines[1], "  Memory  ");
+
+    // Trimming recovers the label
+    assert_eq!(lines[2].trim(), "Disk");
+}
+
+#[test]
+fn test_expand_tabs_aligns_columns() {
+    let source = "id\tname\nlonger\tx\n1\ty";
+    let expanded = expand_tabs(source, 8);
+
+    // The second column starts at the same offset on every line
+    for line in expanded.lines() {
+        assert_eq!(line.chars().nth(8).map(|c| c != ' '), Some(true));
+    }
+    assert_eq!(count_char(&expanded, '\t'), 0);
+}
+
+#[test]
+fn test_acronym_integration() {
+    let names = [
+        "central processing unit",
+        "Random access memory",
+        "graphics processing unit",
+    ];
+    let acronyms: Vec<String> = names.iter().map(|n| acronym(n)).collect();
+    assert_eq!(acronyms, ["CPU", "RAM", "GPU"]);
+
+    // One letter per word that starts with a letter
+    let phrase = "the 2 quick foxes";
+    assert_eq!(acronym(phrase).len(), 3);
+}
+
+#[test]
+fn test_levenshtein_within_fuzzy_search() {
+    let dictionary = ["apple", "apply", "ample", "maple", "applet", "banana"];
+    let query = "appel";
+
+    let matches: Vec<(&str, usize)> = dictionary
+        .iter()
+        .filter_map(|&w| levenshtein_within(query, w, 2).map(|d| (w, d)))
+        .collect();
+
+    // Same result as filtering on the full distance
+    let expected: Vec<(&str, usize)> = dictionary
+        .iter()
+        .map(|&w| (w, levenshtein(query, w)))
+        .filter(|&(_, d)| d <= 2)
+        .collect();
+    assert_eq!(matches, expected);
+    assert!(!matches.iter().any(|&(w, _)| w == "banana"));
+}
+
+#[test]
+fn test_longest_palindromic_substring_integration() {
+    let text = "racecars and levels";
+    let longest = longest_palindromic_substring(text);
+    assert_eq!(longest, "racecar");
+
+    // The result is always a palindrome contained in the input
+    for input in ["forgeeksskeegfor", "abacdfgdcaba", "noon at midday"] {
+        let p = longest_palindromic_substring(input);
+        assert!(input.contains(&p));
+        assert_eq!(p, reverse_string(&p));
+    }
+}
+
+#[test]
+fn test_edit_script_inline_diff() {
+    // Render a simple inline diff: [-removed-]{+added+}
+    let ops = edit_script("color", "colour");
+    let rendered: String = ops
+        .iter()
+        .map(|op| match op {
+            EditOp::Keep(c) => c.to_string(),
+            EditOp::Insert(c) => format!("{{+{}+}}", c),
+            EditOp::Delete(c) => format!("[-{}-]", c),
+        })
+        .collect();
+    assert_eq!(rendered, "colo{+u+}r");
+
+    // Identical strings are all Keep
+    assert!(
+        edit_script("same", "same")
+            .iter()
+            .all(|op| matches!(op, EditOp::Keep(_)))
+    );
+}
+
+#[test]
+fn test_split_keep_delimiter_tokenizer() {
+    let expr = "1+22+333";
+    let tokens = split_keep_delimiter(expr, '+');
+    assert_eq!(tokens, vec!["1", "+", "22", "+", "333"]);
+
+    // Joining the pieces reproduces the input
+    assert_eq!(join_with_capacity(&tokens, ""), expr);
+    assert_eq!(
+        tokens.iter().filter(|&&t| t == "+").count(),
+        count_char(expr, '+')
+    );
+}
+
+#[test]
+fn test_graphemes_truncation() {
+    let label = "Cafe\u{301} 🇪🇸 👩‍💻 menu";
+
+    // Truncate to 6 user-perceived characters without splitting any cluster
+    let truncated: String = graphemes(label).into_iter().take(6).collect();
+    assert_eq!(truncated, "Cafe\u{301} 🇪🇸");
+    assert_eq!(display_width(&truncated), 6);
+
+    assert_eq!(nth_grapheme(label, 3), Some("e\u{301}"));
+    assert_eq!(nth_grapheme(label, 7), Some("👩‍💻"));
+}
+
+#[test]
+fn test_wrap_text_hyphenate_integration() {
+    let text = "Supercalifragilisticexpialidocious is quite a long word indeed";
+    let lines = wrap_text_hyphenate(text, 12);
+
+    // No line exceeds the width
+    assert!(lines.iter().all(|l| l.chars().count() <= 12));
+
+    // Removing the hyphens and rejoining restores the words
+    let rejoined: String = lines
+        .iter()
+        .map(|l| match l.strip_suffix('-') {
+            Some(piece) => piece.to_string(),
+            None => format!("{} ", l),
+        })
+        .collect();
+    assert_eq!(rejoined.trim_end(), text);
+}
+
+#[test]
+fn test_is_balanced_integration() {
+    let snippets = [
+        ("fn main() { println!(\"{:?}\", vec![1, 2]); }", true),
+        ("if (a[0] > b) { return; }", true),
+        ("let x = (1 + [2 * 3);]", false),
+        ("}{", false),
+    ];
+
+    for (snippet, expected) in snippets {
+        assert_eq!(is_balanced(snippet), expected, "{}", snippet);
+    }
+}